@@ -0,0 +1,80 @@
+use rand::prelude::*;
+
+
+/// Rule deciding whether a trajectory method moves to a candidate solution.
+/// Scores are fitness values, lower is better.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Acceptance {
+    /// Simulated annealing's Metropolis test: improvements are always taken,
+    /// a worsening by `delta` with probability `exp(-delta / T)`.
+    #[default]
+    Metropolis,
+    /// Dueck and Scheuer's threshold accepting: any move worsening the score
+    /// by less than the temperature, used as a deterministic threshold.
+    ThresholdAccepting,
+    /// Dueck's great deluge: any candidate at or below a water level that
+    /// starts at the initial score and falls by `rain` every move.
+    GreatDeluge { rain: f64 },
+    /// Dueck's record-to-record travel: any candidate within `deviation` of
+    /// the best score found so far.
+    RecordToRecord { deviation: f64 },
+    /// Burke and Bykov's late acceptance hill climbing: any candidate no worse
+    /// than the current solution or than the current solution `length` moves ago.
+    LateAcceptance { length: usize },
+    /// Only moves that do not worsen the score.
+    HillClimbing,
+}
+
+/// An `Acceptance` rule together with the state it keeps over a run.
+#[derive(Clone, Debug)]
+pub struct Acceptor {
+    pub acceptance: Acceptance,
+    level: f64,
+    history: Vec<f64>,
+    moves: usize,
+}
+
+impl Acceptor {
+    pub fn new(acceptance: Acceptance, initial_score: f64) -> Self {
+        let history = match acceptance {
+            Acceptance::LateAcceptance { length } => vec![initial_score; length.max(1)],
+            _ => Vec::new(),
+        };
+        Self {
+            acceptance,
+            level: initial_score,
+            history,
+            moves: 0,
+        }
+    }
+
+    /// Decides whether to move from a solution scoring `current` to one
+    /// scoring `candidate`, given the best score so far and the temperature.
+    pub fn accept(&mut self, current: f64, candidate: f64, best: f64, temperature: f64, rng: &mut ThreadRng) -> bool {
+        let delta = candidate - current;
+        let accepted = match self.acceptance {
+            Acceptance::Metropolis => delta < 0.0 || rng.gen::<f64>() < (-delta / temperature).exp(),
+            Acceptance::ThresholdAccepting => delta < temperature,
+            Acceptance::GreatDeluge { rain } => {
+                let accepted = candidate <= self.level;
+                self.level -= rain;
+                accepted
+            }
+            Acceptance::RecordToRecord { deviation } => candidate <= best + deviation,
+            Acceptance::LateAcceptance { .. } => {
+                let slot = self.moves % self.history.len();
+                let accepted = delta <= 0.0 || candidate <= self.history[slot];
+                self.history[slot] = if accepted { candidate } else { current };
+                accepted
+            }
+            Acceptance::HillClimbing => delta <= 0.0,
+        };
+        self.moves += 1;
+        accepted
+    }
+
+    /// Current water level of the great deluge.
+    pub fn level(&self) -> f64 {
+        self.level
+    }
+}
@@ -1,16 +1,20 @@
 use rand::prelude::*;
+use std::sync::Arc;
 use crate::optimizer::Optimizer;
 use crate::archive::Archive;
 use crate::individuals::TourIndividual;
 use crate::observer::Observer;
 
+/// Ant colony optimisation for the travelling salesman problem. The tours
+/// share the colony's distance matrix, so they can be refined further by any
+/// optimizer working on `TourIndividual`.
 pub struct AntColony {
     pub num_ants: usize,
     pub num_iterations: usize,
     pub alpha: f64,
     pub beta: f64,
     pub evaporation_rate: f64,
-    pub distance_matrix: Vec<Vec<f64>>,
+    pub distance_matrix: Arc<Vec<Vec<f64>>>,
 }
 
 impl AntColony {
@@ -28,7 +32,7 @@ impl AntColony {
             alpha,
             beta,
             evaporation_rate,
-            distance_matrix,
+            distance_matrix: Arc::new(distance_matrix),
         }
     }
 }
@@ -60,20 +64,21 @@ impl Optimizer<TourIndividual> for AntColony {
             for observer in observers.iter_mut() {
                 observer.on_iteration(iteration, &all_tours);
             }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
 
             // Evaporate pheromones
-            for i in 0..num_nodes {
-                for j in 0..num_nodes {
-                    pheromones[i][j] *= 1.0 - self.evaporation_rate;
-                    pheromones[i][j] = pheromones[i][j].max(0.1);
+            for row in pheromones.iter_mut() {
+                for pheromone in row.iter_mut() {
+                    *pheromone *= 1.0 - self.evaporation_rate;
+                    *pheromone = pheromone.max(0.1);
                 }
             }
 
             // Update pheromones based on ant tours
             all_tours.iter().for_each(|tour| {
-                for k in 0..tour.tour.len() - 1 {
-                    let i = tour.tour[k];
-                    let j = tour.tour[k + 1];
+                for (&i, &j) in tour.tour.iter().zip(tour.tour.iter().cycle().skip(1)) {
                     pheromones[i][j] += 1.0 / tour.length;
                     pheromones[j][i] += 1.0 / tour.length;
                 }
@@ -87,17 +92,16 @@ impl Optimizer<TourIndividual> for AntColony {
 }
 
 impl AntColony {
-    fn construct_solution(&self, pheromones: &Vec<Vec<f64>>) -> TourIndividual {
+    fn construct_solution(&self, pheromones: &[Vec<f64>]) -> TourIndividual {
         let num_nodes = self.distance_matrix.len();
         let mut rng = thread_rng();
-        let mut tour = Vec::with_capacity(num_nodes + 1);
+        let mut tour = Vec::with_capacity(num_nodes);
         let mut visited = vec![false; num_nodes];
 
         let start_node = rng.gen_range(0..num_nodes);
         tour.push(start_node);
         visited[start_node] = true;
         let mut current_node = start_node;
-        let mut total_length = 0.0;
 
         while tour.len() < num_nodes {
             let probabilities = self.calculate_probabilities(
@@ -108,22 +112,17 @@ impl AntColony {
             let next_node = self.select_next_node(&probabilities, &mut rng);
             tour.push(next_node);
             visited[next_node] = true;
-            total_length += self.distance_matrix[current_node][next_node];
             current_node = next_node;
         }
 
-        // Return to start node
-        tour.push(start_node);
-        total_length += self.distance_matrix[current_node][start_node];
-
-        TourIndividual::new(tour, total_length)
+        TourIndividual::from_permutation(tour, Arc::clone(&self.distance_matrix))
     }
 
     fn calculate_probabilities(
         &self,
         current_node: usize,
-        visited: &Vec<bool>,
-        pheromones: &Vec<Vec<f64>>,
+        visited: &[bool],
+        pheromones: &[Vec<f64>],
     ) -> Vec<(usize, f64)> {
         let mut probabilities = Vec::new();
         let mut sum = 0.0;
@@ -148,7 +147,7 @@ impl AntColony {
 
     fn select_next_node(
         &self,
-        probabilities: &Vec<(usize, f64)>,
+        probabilities: &[(usize, f64)],
         rng: &mut ThreadRng,
     ) -> usize {
         let mut cumulative_prob = 0.0;
@@ -4,6 +4,12 @@ pub trait Archive {
 
     fn add(&mut self, solution: Self::Solution);
     fn get_best(&self) -> Option<&Self::Solution>;
+
+    /// Returns every solution currently held by the archive, best first where
+    /// the archive keeps an order. Defaults to just the best solution.
+    fn solutions(&self) -> Vec<&Self::Solution> {
+        self.get_best().into_iter().collect()
+    }
 }
 
 pub struct BasicArchive<I>
@@ -44,4 +50,8 @@ where
     fn get_best(&self) -> Option<&Self::Solution> {
         self.entries.first()
     }
+
+    fn solutions(&self) -> Vec<&Self::Solution> {
+        self.entries.iter().collect()
+    }
 }
@@ -0,0 +1,51 @@
+use rand::prelude::*;
+
+
+/// How a value that left its `(low, high)` interval is brought back inside.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BoundHandling {
+    /// Move the value to the nearest bound.
+    #[default]
+    Clip,
+    /// Mirror the value back across the violated bound.
+    Reflect,
+    /// Re-enter from the opposite bound, treating the interval as periodic.
+    Wrap,
+    /// Draw a new value. Operators that can redraw their own perturbation do
+    /// so; otherwise the value is drawn uniformly from the interval.
+    Resample,
+}
+
+impl BoundHandling {
+    pub fn apply(&self, value: f64, (low, high): (f64, f64), rng: &mut ThreadRng) -> f64 {
+        if (low..=high).contains(&value) {
+            return value;
+        }
+
+        let width = high - low;
+        if width <= 0.0 {
+            return low;
+        }
+
+        match self {
+            BoundHandling::Clip => value.clamp(low, high),
+            BoundHandling::Reflect => {
+                let offset = (value - low).rem_euclid(2.0 * width);
+                if offset > width {
+                    low + 2.0 * width - offset
+                } else {
+                    low + offset
+                }
+            }
+            BoundHandling::Wrap => low + (value - low).rem_euclid(width),
+            BoundHandling::Resample => rng.gen_range(low..=high),
+        }
+    }
+
+    /// Repairs every gene of `genes` against the matching entry of `bounds`.
+    pub fn repair(&self, genes: &mut [f64], bounds: &[(f64, f64)], rng: &mut ThreadRng) {
+        for (gene, &bound) in genes.iter_mut().zip(bounds) {
+            *gene = self.apply(*gene, bound, rng);
+        }
+    }
+}
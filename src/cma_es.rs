@@ -0,0 +1,423 @@
+use rand::prelude::*;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{RealVector, FitnessValue};
+use crate::observer::Observer;
+use crate::initializer::Initializer;
+use crate::bounds::BoundHandling;
+use crate::sampling::UniformSampler;
+use crate::random::standard_normal;
+
+
+/// Restart policy for `CmaEs` once a run has converged or stalled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Restarts {
+    None,
+    /// Auger and Hansen's IPOP: each restart doubles the population size.
+    Ipop { max_restarts: usize },
+    /// Hansen's BIPOP: alternates IPOP-style runs with runs using a small
+    /// random population and step size, giving each regime a similar budget.
+    Bipop { max_restarts: usize },
+}
+
+/// Covariance matrix adaptation evolution strategy (Hansen's `(mu/mu_w, lambda)`
+/// CMA-ES with cumulative step-size adaptation).
+///
+/// The first run starts from the initializer's individual with step size
+/// `sigma`; restarts start from newly generated individuals. The search
+/// stops after `max_evaluations` fitness evaluations. Samples outside `bounds`
+/// are repaired with `handling` before evaluation.
+///
+/// Observers receive every generation's offspring, numbered across restarts,
+/// together with the metrics `sigma`, `mean_<i>` for each coordinate of the
+/// distribution mean, and `restart` (the run index) when a run begins.
+pub struct CmaEs<I>
+where
+    I: RealVector,
+{
+    pub sigma: f64,
+    /// Offspring per generation; `None` uses `4 + floor(3 ln n)`.
+    pub population_size: Option<usize>,
+    pub max_evaluations: usize,
+    pub bounds: Vec<(f64, f64)>,
+    pub handling: BoundHandling,
+    pub restarts: Restarts,
+    /// A run stops when the step size times the largest axis falls below this.
+    pub tolerance_x: f64,
+    /// A run stops when the fitness range of a generation and of recent best
+    /// values falls below this.
+    pub tolerance_fitness: f64,
+    pub initializer: Initializer<I>,
+}
+
+impl<I> CmaEs<I>
+where
+    I: RealVector + Default + 'static,
+{
+    /// Starts from a uniform point in `bounds` with a step size of a third of
+    /// the mean bound width.
+    pub fn new(max_evaluations: usize, bounds: Vec<(f64, f64)>) -> Self {
+        let initializer = Initializer::sampled(UniformSampler, bounds.clone());
+        Self::from_initializer(initializer, max_evaluations, bounds)
+    }
+}
+
+impl<I> CmaEs<I>
+where
+    I: RealVector,
+{
+    pub fn from_initializer(initializer: Initializer<I>, max_evaluations: usize, bounds: Vec<(f64, f64)>) -> Self {
+        let width = bounds.iter().map(|(low, high)| high - low).sum::<f64>() / bounds.len().max(1) as f64;
+        Self {
+            sigma: width / 3.0,
+            population_size: None,
+            max_evaluations,
+            bounds,
+            handling: BoundHandling::Clip,
+            restarts: Restarts::None,
+            tolerance_x: 1e-12,
+            tolerance_fitness: 1e-12,
+            initializer,
+        }
+    }
+
+    pub fn with_sigma(mut self, sigma: f64) -> Self {
+        self.sigma = sigma;
+        self
+    }
+
+    pub fn with_population_size(mut self, population_size: usize) -> Self {
+        self.population_size = Some(population_size);
+        self
+    }
+
+    pub fn with_bound_handling(mut self, handling: BoundHandling) -> Self {
+        self.handling = handling;
+        self
+    }
+
+    pub fn with_restarts(mut self, restarts: Restarts) -> Self {
+        self.restarts = restarts;
+        self
+    }
+
+    fn default_population(&self, dimensions: usize) -> usize {
+        self.population_size
+            .unwrap_or(4 + (3.0 * (dimensions as f64).ln()).floor() as usize)
+            .max(2)
+    }
+}
+
+/// Outcome of a single CMA-ES run.
+struct Run {
+    evaluations: usize,
+}
+
+/// Mutable state shared by the runs of one `optimize` call.
+struct Progress<'a, A, O> {
+    archive: &'a mut A,
+    observers: &'a mut [O],
+    generation: usize,
+    evaluations: usize,
+}
+
+impl<I> CmaEs<I>
+where
+    I: RealVector,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn run<A, O>(
+        &self,
+        template: &I,
+        sigma: f64,
+        lambda: usize,
+        budget: usize,
+        progress: &mut Progress<'_, A, O>,
+        rng: &mut ThreadRng,
+    ) -> Run
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut mean = template.genes().to_vec();
+        let n = mean.len();
+        let nf = n as f64;
+        let mut sigma = sigma;
+
+        let mu = lambda / 2;
+        let raw: Vec<f64> = (0..mu).map(|i| (mu as f64 + 0.5).ln() - ((i + 1) as f64).ln()).collect();
+        let total: f64 = raw.iter().sum();
+        let weights: Vec<f64> = raw.iter().map(|w| w / total).collect();
+        let mueff = 1.0 / weights.iter().map(|w| w * w).sum::<f64>();
+
+        let cc = (4.0 + mueff / nf) / (nf + 4.0 + 2.0 * mueff / nf);
+        let cs = (mueff + 2.0) / (nf + mueff + 5.0);
+        let c1 = 2.0 / ((nf + 1.3).powi(2) + mueff);
+        let cmu = (1.0 - c1).min(2.0 * (mueff - 2.0 + 1.0 / mueff) / ((nf + 2.0).powi(2) + mueff));
+        let damps = 1.0 + 2.0 * (((mueff - 1.0) / (nf + 1.0)).sqrt() - 1.0).max(0.0) + cs;
+        let chi_n = nf.sqrt() * (1.0 - 1.0 / (4.0 * nf) + 1.0 / (21.0 * nf * nf));
+
+        let mut pc = vec![0.0; n];
+        let mut ps = vec![0.0; n];
+        let mut c = identity(n);
+        let mut b = identity(n);
+        let mut d = vec![1.0; n];
+        let mut eigen_generation = 0;
+        let mut recent_best: Vec<f64> = Vec::new();
+        let history = 10 + (30.0 * nf / lambda as f64).ceil() as usize;
+
+        let mut evaluations = 0;
+        let mut generation: usize = 0;
+        while evaluations + lambda <= budget {
+            generation += 1;
+
+            // Sample and evaluate lambda offspring.
+            let mut offspring: Vec<(f64, Vec<f64>, I)> = Vec::with_capacity(lambda);
+            for _ in 0..lambda {
+                let z: Vec<f64> = (0..n).map(|_| standard_normal(rng)).collect();
+                let mut x: Vec<f64> = (0..n)
+                    .map(|i| mean[i] + sigma * (0..n).map(|j| b[i][j] * d[j] * z[j]).sum::<f64>())
+                    .collect();
+                self.handling.repair(&mut x, &self.bounds, rng);
+                let y: Vec<f64> = (0..n).map(|i| (x[i] - mean[i]) / sigma).collect();
+
+                let mut individual = template.clone();
+                *individual.genes_mut() = x;
+                let score = individual.fitness().to_f64();
+                offspring.push((score, y, individual));
+            }
+            evaluations += lambda;
+            offspring.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+            // Move the mean towards the best mu steps.
+            let step: Vec<f64> = (0..n)
+                .map(|i| weights.iter().zip(&offspring).map(|(w, (_, y, _))| w * y[i]).sum())
+                .collect();
+            for i in 0..n {
+                mean[i] += sigma * step[i];
+            }
+
+            // Cumulate the evolution paths.
+            let whitened: Vec<f64> = {
+                let projected: Vec<f64> = (0..n).map(|j| (0..n).map(|i| b[i][j] * step[i]).sum::<f64>() / d[j]).collect();
+                (0..n).map(|i| (0..n).map(|j| b[i][j] * projected[j]).sum()).collect()
+            };
+            let path_scale = (cs * (2.0 - cs) * mueff).sqrt();
+            for i in 0..n {
+                ps[i] = (1.0 - cs) * ps[i] + path_scale * whitened[i];
+            }
+            let ps_norm = norm(&ps);
+            let hsig = ps_norm / (1.0 - (1.0 - cs).powi(2 * generation as i32)).sqrt() / chi_n < 1.4 + 2.0 / (nf + 1.0);
+            let hsig_value = if hsig { 1.0 } else { 0.0 };
+            let pc_scale = (cc * (2.0 - cc) * mueff).sqrt();
+            for i in 0..n {
+                pc[i] = (1.0 - cc) * pc[i] + hsig_value * pc_scale * step[i];
+            }
+
+            // Rank-one and rank-mu covariance update.
+            let correction = (1.0 - hsig_value) * cc * (2.0 - cc);
+            for i in 0..n {
+                for j in 0..=i {
+                    let rank_mu: f64 = weights.iter().zip(&offspring).map(|(w, (_, y, _))| w * y[i] * y[j]).sum();
+                    let value = (1.0 - c1 - cmu) * c[i][j]
+                        + c1 * (pc[i] * pc[j] + correction * c[i][j])
+                        + cmu * rank_mu;
+                    c[i][j] = value;
+                    c[j][i] = value;
+                }
+            }
+
+            sigma *= ((cs / damps) * (ps_norm / chi_n - 1.0)).exp();
+
+            // The decomposition is refreshed often enough to keep its cost
+            // below that of the evaluations.
+            if (generation - eigen_generation) as f64 > lambda as f64 / (c1 + cmu) / nf / 10.0 {
+                eigen_generation = generation;
+                let (values, vectors) = symmetric_eigen(&c);
+                d = values.iter().map(|v| v.max(1e-20).sqrt()).collect();
+                b = vectors;
+            }
+
+            progress.generation += 1;
+            progress.evaluations += lambda;
+            let population: Vec<I> = offspring.iter().map(|(_, _, individual)| individual.clone()).collect();
+            for individual in &population {
+                progress.archive.add(individual.clone());
+            }
+            for observer in progress.observers.iter_mut() {
+                observer.on_iteration(progress.generation, &population);
+                observer.on_metric(progress.generation, "sigma", sigma);
+                for (i, value) in mean.iter().enumerate() {
+                    observer.on_metric(progress.generation, &format!("mean_{}", i), *value);
+                }
+            }
+            if progress.observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+
+            // Termination criteria of a single run.
+            recent_best.push(offspring[0].0);
+            if recent_best.len() > history {
+                recent_best.remove(0);
+            }
+            let spread = |values: &mut dyn Iterator<Item = f64>| {
+                let (low, high) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)));
+                high - low
+            };
+            let generation_spread = spread(&mut offspring.iter().map(|(score, _, _)| *score));
+            let history_spread = spread(&mut recent_best.iter().cloned());
+            let max_axis = d.iter().cloned().fold(0.0, f64::max);
+            let min_axis = d.iter().cloned().fold(f64::INFINITY, f64::min);
+
+            let converged = recent_best.len() >= history
+                && generation_spread.max(history_spread) < self.tolerance_fitness;
+            let collapsed = sigma * max_axis < self.tolerance_x;
+            let ill_conditioned = (max_axis / min_axis).powi(2) > 1e14;
+            if converged || collapsed || ill_conditioned || !sigma.is_finite() {
+                break;
+            }
+        }
+
+        Run { evaluations }
+    }
+}
+
+impl<I> Optimizer<I> for CmaEs<I>
+where
+    I: RealVector,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut rng = thread_rng();
+        let first = self.initializer.individual(&mut rng);
+        let dimensions = first.genes().len();
+        let default_lambda = self.default_population(dimensions);
+
+        for observer in observers.iter_mut() {
+            observer.on_start();
+            observer.on_iteration(0, std::slice::from_ref(&first));
+        }
+
+        let mut progress = Progress {
+            archive,
+            observers,
+            generation: 0,
+            evaluations: 0,
+        };
+
+        let max_restarts = match self.restarts {
+            Restarts::None => 0,
+            Restarts::Ipop { max_restarts } | Restarts::Bipop { max_restarts } => max_restarts,
+        };
+        let mut large_lambda = default_lambda;
+        let (mut large_budget, mut small_budget) = (0, 0);
+
+        for restart in 0..=max_restarts {
+            let remaining = self.max_evaluations - progress.evaluations;
+            if remaining < default_lambda || progress.observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+
+            let template = if restart == 0 { first.clone() } else { self.initializer.generate(&mut rng) };
+            let (lambda, sigma, small) = match self.restarts {
+                Restarts::Bipop { .. } if restart > 0 && small_budget < large_budget => {
+                    let u: f64 = rng.gen();
+                    let ratio = 0.5 * large_lambda as f64 / default_lambda as f64;
+                    let lambda = (default_lambda as f64 * ratio.powf(u * u)).floor() as usize;
+                    (lambda.max(default_lambda), self.sigma * 10f64.powf(-2.0 * rng.gen::<f64>()), true)
+                }
+                Restarts::None => (default_lambda, self.sigma, false),
+                _ => {
+                    if restart > 0 {
+                        large_lambda *= 2;
+                    }
+                    (large_lambda, self.sigma, false)
+                }
+            };
+            if lambda > remaining {
+                break;
+            }
+
+            for observer in progress.observers.iter_mut() {
+                observer.on_metric(progress.generation, "restart", restart as f64);
+            }
+
+            let run = self.run(&template, sigma, lambda, remaining, &mut progress, &mut rng);
+            if small {
+                small_budget += run.evaluations;
+            } else {
+                large_budget += run.evaluations;
+            }
+            if run.evaluations == 0 {
+                break;
+            }
+        }
+
+        for observer in progress.observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
+
+fn identity(n: usize) -> Vec<Vec<f64>> {
+    (0..n).map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect()).collect()
+}
+
+fn norm(vector: &[f64]) -> f64 {
+    vector.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+/// Eigen-decomposition of a symmetric matrix by cyclic Jacobi rotations.
+/// Returns the eigenvalues and a matrix whose columns are the matching
+/// unit eigenvectors.
+#[allow(clippy::needless_range_loop)]
+pub fn symmetric_eigen(matrix: &[Vec<f64>]) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let n = matrix.len();
+    let mut a: Vec<Vec<f64>> = matrix.to_vec();
+    let mut v = identity(n);
+
+    for _ in 0..100 {
+        let off_diagonal: f64 = (0..n)
+            .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+            .map(|(i, j)| a[i][j] * a[i][j])
+            .sum();
+        let scale: f64 = (0..n).map(|i| a[i][i] * a[i][i]).sum::<f64>().max(f64::MIN_POSITIVE);
+        if off_diagonal <= 1e-30 * scale {
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p][q].abs() < f64::MIN_POSITIVE {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let cos = 1.0 / (t * t + 1.0).sqrt();
+                let sin = t * cos;
+
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = cos * akp - sin * akq;
+                    row[q] = sin * akp + cos * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p][k], a[q][k]);
+                    a[p][k] = cos * apk - sin * aqk;
+                    a[q][k] = sin * apk + cos * aqk;
+                }
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = cos * vkp - sin * vkq;
+                    row[q] = sin * vkp + cos * vkq;
+                }
+            }
+        }
+    }
+
+    ((0..n).map(|i| a[i][i]).collect(), v)
+}
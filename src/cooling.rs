@@ -0,0 +1,114 @@
+/// What happened during the Markov chain just finished at one temperature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChainStatistics {
+    /// Number of chains completed so far, starting at 1.
+    pub level: usize,
+    /// Fraction of the run completed.
+    pub progress: f64,
+    /// Share of the chain's moves that were accepted.
+    pub acceptance: f64,
+    /// Consecutive chains, up to this one, that did not improve the best solution.
+    pub stagnant: usize,
+}
+
+/// How `SimulatedAnnealing` lowers its temperature after each Markov chain.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CoolingSchedule {
+    /// `T <- T * (1 - rate)`.
+    Geometric { rate: f64 },
+    /// Falls linearly from the initial temperature to `end` over the run.
+    Linear { end: f64 },
+    /// `T = T0 * ln 2 / ln(k + 2)` after `k` chains; the slow schedule of
+    /// Geman and Geman's convergence proof.
+    Logarithmic,
+    /// Lundy and Mees: `T <- T / (1 + beta * T)`.
+    LundyMees { beta: f64 },
+    /// Acceptance-ratio driven: the target acceptance ratio falls linearly
+    /// from `initial_ratio` to `final_ratio` over the run, and the temperature
+    /// is multiplied by `factor` (below 1) while the observed ratio is above
+    /// the target and divided by it while below.
+    Adaptive { initial_ratio: f64, final_ratio: f64, factor: f64 },
+    /// Geometric cooling at `rate` that, after `patience` chains without a
+    /// new best solution, reheats to `reheat` times the initial temperature.
+    Reheating { rate: f64, patience: usize, reheat: f64 },
+}
+
+impl CoolingSchedule {
+    /// Returns the temperature for the next chain.
+    pub fn next(&self, initial: f64, current: f64, chain: &ChainStatistics) -> f64 {
+        let progress = chain.progress.clamp(0.0, 1.0);
+        let next = match *self {
+            CoolingSchedule::Geometric { rate } => current * (1.0 - rate),
+            CoolingSchedule::Linear { end } => initial + (end - initial) * progress,
+            CoolingSchedule::Logarithmic => initial * 2f64.ln() / (chain.level as f64 + 2.0).ln(),
+            CoolingSchedule::LundyMees { beta } => current / (1.0 + beta * current),
+            CoolingSchedule::Adaptive { initial_ratio, final_ratio, factor } => {
+                let target = initial_ratio + (final_ratio - initial_ratio) * progress;
+                if chain.acceptance > target {
+                    current * factor
+                } else {
+                    current / factor
+                }
+            }
+            CoolingSchedule::Reheating { rate, patience, reheat } => {
+                if patience > 0 && chain.stagnant >= patience {
+                    initial * reheat
+                } else {
+                    current * (1.0 - rate)
+                }
+            }
+        };
+        next.max(0.0)
+    }
+}
+
+/// Temperature at which the Metropolis test accepts, on average, the given
+/// share of the uphill moves with cost increases `deltas`. Non-positive
+/// deltas are ignored; returns `None` when none are left or `acceptance` is
+/// not strictly between 0 and 1.
+pub fn calibrate_temperature(deltas: &[f64], acceptance: f64) -> Option<f64> {
+    let uphill: Vec<f64> = deltas.iter().cloned().filter(|&delta| delta > 0.0).collect();
+    if uphill.is_empty() || acceptance <= 0.0 || acceptance >= 1.0 {
+        return None;
+    }
+
+    let mean_acceptance = |temperature: f64| {
+        uphill.iter().map(|delta| (-delta / temperature).exp()).sum::<f64>() / uphill.len() as f64
+    };
+
+    // At -d / ln(p) a move of cost d is accepted with probability p, so the
+    // smallest and largest delta bracket the answer.
+    let (smallest, largest) = uphill
+        .iter()
+        .fold((f64::INFINITY, 0.0f64), |(low, high), &delta| (low.min(delta), high.max(delta)));
+    let mut low = -smallest / acceptance.ln();
+    let mut high = -largest / acceptance.ln();
+    for _ in 0..100 {
+        let middle = 0.5 * (low + high);
+        if mean_acceptance(middle) < acceptance {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Some(0.5 * (low + high))
+}
+
+/// Threshold at which threshold accepting, which takes any move worsening
+/// the score by less than the threshold, accepts the given share of the
+/// uphill moves with cost increases `deltas`: just above the matching
+/// quantile of the uphill deltas. Returns `None` in the same cases as
+/// `calibrate_temperature`.
+pub fn calibrate_threshold(deltas: &[f64], acceptance: f64) -> Option<f64> {
+    let mut uphill: Vec<f64> = deltas.iter().cloned().filter(|&delta| delta > 0.0).collect();
+    if uphill.is_empty() || acceptance <= 0.0 || acceptance >= 1.0 {
+        return None;
+    }
+
+    uphill.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let accepted = ((acceptance * uphill.len() as f64).ceil() as usize).clamp(1, uphill.len());
+    // One ulp above the quantile; the delta is positive, so the next bit
+    // pattern is the next larger float.
+    let quantile = uphill[accepted - 1];
+    Some(f64::from_bits(quantile.to_bits() + 1))
+}
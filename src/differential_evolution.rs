@@ -0,0 +1,405 @@
+use rand::prelude::*;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{RealVector, FitnessValue};
+use crate::observer::Observer;
+use crate::initializer::Initializer;
+use crate::bounds::BoundHandling;
+use crate::sampling::UniformSampler;
+use crate::random::{standard_normal, standard_cauchy};
+
+
+/// Classic DE mutation and crossover schemes, named `DE/x/y/z`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    /// `v = x_r1 + F (x_r2 - x_r3)`, binomial crossover.
+    Rand1Bin,
+    /// `v = x_best + F (x_r1 - x_r2)`, binomial crossover.
+    Best1Bin,
+    /// `v = x_i + F (x_best - x_i) + F (x_r1 - x_r2)`, binomial crossover.
+    CurrentToBest1Bin,
+    /// `v = x_r1 + F (x_r2 - x_r3) + F (x_r4 - x_r5)`, exponential crossover.
+    Rand2Exp,
+}
+
+/// Fixed-parameter DE or one of the parameter-adaptive variants. JADE and
+/// SHADE use the current-to-pbest/1/bin scheme with an external archive of
+/// replaced parents, picking the guiding individual among the best `p`
+/// fraction of the population.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Variant {
+    Classic(Strategy),
+    /// Zhang and Sanderson's JADE: `F` and `CR` are drawn around means that
+    /// move towards successful values at learning rate `c`.
+    Jade { p: f64, c: f64 },
+    /// Tanabe and Fukunaga's SHADE: `F` and `CR` are drawn around a
+    /// historical memory of `memory_size` successful means.
+    Shade { p: f64, memory_size: usize },
+}
+
+impl Variant {
+    pub fn jade() -> Self {
+        Variant::Jade { p: 0.05, c: 0.1 }
+    }
+
+    pub fn shade() -> Self {
+        Variant::Shade { p: 0.1, memory_size: 10 }
+    }
+
+    fn min_population(&self) -> usize {
+        match self {
+            Variant::Classic(Strategy::Rand2Exp) => 6,
+            _ => 4,
+        }
+    }
+}
+
+/// Differential evolution over real-vector individuals within `bounds`.
+///
+/// `scale` (F) and `crossover_rate` (CR) are used as-is by the classic
+/// strategies and as starting means by JADE and SHADE. Observers receive the
+/// mean `F` and `CR` used in each generation as the `scale` and
+/// `crossover_rate` metrics.
+pub struct DifferentialEvolution<I>
+where
+    I: RealVector,
+{
+    pub population_size: usize,
+    pub generations: usize,
+    pub scale: f64,
+    pub crossover_rate: f64,
+    pub variant: Variant,
+    pub bounds: Vec<(f64, f64)>,
+    pub handling: BoundHandling,
+    pub initializer: Initializer<I>,
+}
+
+impl<I> DifferentialEvolution<I>
+where
+    I: RealVector + Default + 'static,
+{
+    /// DE/rand/1/bin with `F = 0.5` and `CR = 0.9`, starting from a uniform
+    /// sample of `bounds`.
+    pub fn new(population_size: usize, generations: usize, bounds: Vec<(f64, f64)>) -> Self {
+        let initializer = Initializer::sampled(UniformSampler, bounds.clone());
+        Self::from_initializer(initializer, population_size, generations, bounds)
+    }
+}
+
+impl<I> DifferentialEvolution<I>
+where
+    I: RealVector,
+{
+    pub fn from_initializer(
+        initializer: Initializer<I>,
+        population_size: usize,
+        generations: usize,
+        bounds: Vec<(f64, f64)>,
+    ) -> Self {
+        Self {
+            population_size,
+            generations,
+            scale: 0.5,
+            crossover_rate: 0.9,
+            variant: Variant::Classic(Strategy::Rand1Bin),
+            bounds,
+            handling: BoundHandling::Clip,
+            initializer,
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: Strategy) -> Self {
+        self.variant = Variant::Classic(strategy);
+        self
+    }
+
+    pub fn with_variant(mut self, variant: Variant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    pub fn with_parameters(mut self, scale: f64, crossover_rate: f64) -> Self {
+        self.scale = scale;
+        self.crossover_rate = crossover_rate;
+        self
+    }
+
+    pub fn with_bound_handling(mut self, handling: BoundHandling) -> Self {
+        self.handling = handling;
+        self
+    }
+
+    fn mutant(
+        &self,
+        target: usize,
+        population: &[I],
+        order: &[usize],
+        external: &[I],
+        scale: f64,
+        rng: &mut ThreadRng,
+    ) -> Vec<f64> {
+        let x = |index: usize| population[index].genes();
+        let difference = |a: &[f64], b: &[f64], d: usize| a[d] - b[d];
+        let dimensions = x(target).len();
+
+        match self.variant {
+            Variant::Classic(strategy) => {
+                let best = order[0];
+                let r = distinct(population.len(), target, 5, rng);
+                (0..dimensions)
+                    .map(|d| match strategy {
+                        Strategy::Rand1Bin => x(r[0])[d] + scale * difference(x(r[1]), x(r[2]), d),
+                        Strategy::Best1Bin => x(best)[d] + scale * difference(x(r[0]), x(r[1]), d),
+                        Strategy::CurrentToBest1Bin => {
+                            x(target)[d]
+                                + scale * difference(x(best), x(target), d)
+                                + scale * difference(x(r[0]), x(r[1]), d)
+                        }
+                        Strategy::Rand2Exp => {
+                            x(r[0])[d]
+                                + scale * difference(x(r[1]), x(r[2]), d)
+                                + scale * difference(x(r[3]), x(r[4]), d)
+                        }
+                    })
+                    .collect()
+            }
+            Variant::Jade { p, .. } | Variant::Shade { p, .. } => {
+                let top = ((p * population.len() as f64).round() as usize).clamp(1, population.len());
+                let pbest = order[rng.gen_range(0..top)];
+                let r1 = distinct(population.len(), target, 1, rng)[0];
+                // r2 comes from the population and the archive combined.
+                let r2 = loop {
+                    let candidate = rng.gen_range(0..population.len() + external.len());
+                    if candidate != target && candidate != r1 {
+                        break candidate;
+                    }
+                };
+                let x_r2 = if r2 < population.len() { x(r2) } else { external[r2 - population.len()].genes() };
+                (0..dimensions)
+                    .map(|d| {
+                        x(target)[d]
+                            + scale * difference(x(pbest), x(target), d)
+                            + scale * difference(x(r1), x_r2, d)
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn crossover(&self, target: &[f64], mutant: &[f64], crossover_rate: f64, rng: &mut ThreadRng) -> Vec<f64> {
+        let dimensions = target.len();
+        let forced = rng.gen_range(0..dimensions.max(1));
+
+        if self.variant == Variant::Classic(Strategy::Rand2Exp) {
+            // Exponential: copy a run of consecutive genes starting at `forced`.
+            let mut trial = target.to_vec();
+            let mut d = forced;
+            for _ in 0..dimensions {
+                trial[d] = mutant[d];
+                d = (d + 1) % dimensions;
+                if rng.gen::<f64>() >= crossover_rate {
+                    break;
+                }
+            }
+            return trial;
+        }
+
+        (0..dimensions)
+            .map(|d| {
+                if d == forced || rng.gen::<f64>() < crossover_rate {
+                    mutant[d]
+                } else {
+                    target[d]
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parameter-adaptation state for JADE and SHADE.
+struct Adaptation {
+    scale_means: Vec<f64>,
+    crossover_means: Vec<f64>,
+    next_slot: usize,
+    successful_scales: Vec<f64>,
+    successful_rates: Vec<f64>,
+    improvements: Vec<f64>,
+}
+
+impl Adaptation {
+    fn new(variant: Variant, scale: f64, crossover_rate: f64) -> Self {
+        let slots = match variant {
+            Variant::Shade { memory_size, .. } => memory_size.max(1),
+            _ => 1,
+        };
+        Self {
+            scale_means: vec![scale; slots],
+            crossover_means: vec![crossover_rate; slots],
+            next_slot: 0,
+            successful_scales: Vec::new(),
+            successful_rates: Vec::new(),
+            improvements: Vec::new(),
+        }
+    }
+
+    /// Draws `F` from a Cauchy and `CR` from a normal distribution around a
+    /// memory slot, both with scale 0.1.
+    fn sample(&self, rng: &mut ThreadRng) -> (f64, f64) {
+        let slot = rng.gen_range(0..self.scale_means.len());
+        let crossover_rate = (self.crossover_means[slot] + 0.1 * standard_normal(rng)).clamp(0.0, 1.0);
+        let scale = loop {
+            let scale = self.scale_means[slot] + 0.1 * standard_cauchy(rng);
+            if scale > 0.0 {
+                break scale.min(1.0);
+            }
+        };
+        (scale, crossover_rate)
+    }
+
+    fn record(&mut self, scale: f64, crossover_rate: f64, improvement: f64) {
+        self.successful_scales.push(scale);
+        self.successful_rates.push(crossover_rate);
+        self.improvements.push(improvement);
+    }
+
+    fn update(&mut self, variant: Variant) {
+        if self.successful_scales.is_empty() {
+            return;
+        }
+
+        match variant {
+            Variant::Jade { c, .. } => {
+                let uniform = vec![1.0; self.successful_scales.len()];
+                let rate = weighted_mean(&self.successful_rates, &uniform);
+                let scale = lehmer_mean(&self.successful_scales, &uniform);
+                self.crossover_means[0] = (1.0 - c) * self.crossover_means[0] + c * rate;
+                self.scale_means[0] = (1.0 - c) * self.scale_means[0] + c * scale;
+            }
+            Variant::Shade { .. } => {
+                let slot = self.next_slot;
+                self.crossover_means[slot] = weighted_mean(&self.successful_rates, &self.improvements);
+                self.scale_means[slot] = lehmer_mean(&self.successful_scales, &self.improvements);
+                self.next_slot = (slot + 1) % self.scale_means.len();
+            }
+            Variant::Classic(_) => {}
+        }
+
+        self.successful_scales.clear();
+        self.successful_rates.clear();
+        self.improvements.clear();
+    }
+}
+
+impl<I> Optimizer<I> for DifferentialEvolution<I>
+where
+    I: RealVector,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        assert!(
+            self.population_size >= self.variant.min_population(),
+            "{:?} needs a population of at least {}",
+            self.variant,
+            self.variant.min_population()
+        );
+
+        let mut rng = thread_rng();
+        let mut population = self.initializer.population(self.population_size, &mut rng);
+        let mut scores: Vec<f64> = population.iter().map(|ind| ind.fitness().to_f64()).collect();
+        let mut external: Vec<I> = Vec::new();
+        let mut adaptation = Adaptation::new(self.variant, self.scale, self.crossover_rate);
+        let adaptive = !matches!(self.variant, Variant::Classic(_));
+
+        for individual in &population {
+            archive.add(individual.clone());
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_start();
+            observer.on_iteration(0, &population);
+        }
+
+        for generation in 1..=self.generations {
+            let mut order: Vec<usize> = (0..population.len()).collect();
+            order.sort_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap());
+
+            let mut next_population = population.clone();
+            let mut next_scores = scores.clone();
+            let (mut scale_sum, mut rate_sum) = (0.0, 0.0);
+
+            for target in 0..population.len() {
+                let (scale, crossover_rate) = if adaptive {
+                    adaptation.sample(&mut rng)
+                } else {
+                    (self.scale, self.crossover_rate)
+                };
+                scale_sum += scale;
+                rate_sum += crossover_rate;
+
+                let mutant = self.mutant(target, &population, &order, &external, scale, &mut rng);
+                let mut genes = self.crossover(population[target].genes(), &mutant, crossover_rate, &mut rng);
+                self.handling.repair(&mut genes, &self.bounds, &mut rng);
+
+                let mut trial = population[target].clone();
+                *trial.genes_mut() = genes;
+                let score = trial.fitness().to_f64();
+                archive.add(trial.clone());
+
+                if score <= scores[target] {
+                    if score < scores[target] && adaptive {
+                        adaptation.record(scale, crossover_rate, scores[target] - score);
+                        external.push(population[target].clone());
+                    }
+                    next_population[target] = trial;
+                    next_scores[target] = score;
+                }
+            }
+
+            while external.len() > self.population_size {
+                external.swap_remove(rng.gen_range(0..external.len()));
+            }
+            adaptation.update(self.variant);
+            population = next_population;
+            scores = next_scores;
+
+            let count = population.len() as f64;
+            for observer in observers.iter_mut() {
+                observer.on_iteration(generation, &population);
+                observer.on_metric(generation, "scale", scale_sum / count);
+                observer.on_metric(generation, "crossover_rate", rate_sum / count);
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
+
+/// `count` distinct indices from `0..n`, all different from `exclude`; fewer
+/// if the population is too small.
+fn distinct(n: usize, exclude: usize, count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+    let count = count.min(n.saturating_sub(1));
+    rand::seq::index::sample(rng, n - 1, count)
+        .into_iter()
+        .map(|index| if index >= exclude { index + 1 } else { index })
+        .collect()
+}
+
+fn weighted_mean(values: &[f64], weights: &[f64]) -> f64 {
+    let total: f64 = weights.iter().sum();
+    values.iter().zip(weights).map(|(v, w)| v * w).sum::<f64>() / total
+}
+
+fn lehmer_mean(values: &[f64], weights: &[f64]) -> f64 {
+    let numerator: f64 = values.iter().zip(weights).map(|(v, w)| w * v * v).sum();
+    let denominator: f64 = values.iter().zip(weights).map(|(v, w)| w * v).sum();
+    numerator / denominator
+}
@@ -19,6 +19,15 @@ where
     }
 }
 
+impl<I> Default for DistributionObserver<I>
+where
+    I: Individual + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<I> Observer<I> for DistributionObserver<I>
 where
     I: Individual + Clone,
@@ -0,0 +1,361 @@
+use rand::prelude::*;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{RealVector, FitnessValue};
+use crate::observer::Observer;
+use crate::initializer::Initializer;
+use crate::bounds::BoundHandling;
+use crate::sampling::UniformSampler;
+use crate::random::standard_normal;
+
+
+/// (1+1)-ES with Rechenberg's 1/5th success rule: every `window` iterations
+/// the step size is divided by `factor` if more than a fifth of the
+/// mutations succeeded and multiplied by it otherwise.
+///
+/// Observers receive the current solution each iteration and the `sigma`
+/// metric.
+pub struct OnePlusOneEs<I>
+where
+    I: RealVector,
+{
+    pub sigma: f64,
+    pub iterations: usize,
+    pub window: usize,
+    pub factor: f64,
+    pub bounds: Vec<(f64, f64)>,
+    pub handling: BoundHandling,
+    pub initializer: Initializer<I>,
+}
+
+impl<I> OnePlusOneEs<I>
+where
+    I: RealVector + Default + 'static,
+{
+    pub fn new(sigma: f64, iterations: usize, bounds: Vec<(f64, f64)>) -> Self {
+        let initializer = Initializer::sampled(UniformSampler, bounds.clone());
+        Self::from_initializer(initializer, sigma, iterations, bounds)
+    }
+}
+
+impl<I> OnePlusOneEs<I>
+where
+    I: RealVector,
+{
+    /// Uses Schwefel's `factor = 0.85` with a window of 10 iterations.
+    pub fn from_initializer(initializer: Initializer<I>, sigma: f64, iterations: usize, bounds: Vec<(f64, f64)>) -> Self {
+        Self {
+            sigma,
+            iterations,
+            window: 10,
+            factor: 0.85,
+            bounds,
+            handling: BoundHandling::Clip,
+            initializer,
+        }
+    }
+
+    pub fn with_success_rule(mut self, window: usize, factor: f64) -> Self {
+        assert!(window >= 1, "the success rule needs a window of at least one iteration");
+        self.window = window;
+        self.factor = factor;
+        self
+    }
+
+    pub fn with_bound_handling(mut self, handling: BoundHandling) -> Self {
+        self.handling = handling;
+        self
+    }
+}
+
+impl<I> Optimizer<I> for OnePlusOneEs<I>
+where
+    I: RealVector,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        assert!(self.window >= 1, "the success rule needs a window of at least one iteration");
+
+        let mut rng = thread_rng();
+        let mut current = self.initializer.individual(&mut rng);
+        let mut score = current.fitness().to_f64();
+        let mut sigma = self.sigma;
+        let mut successes = 0;
+
+        archive.add(current.clone());
+        for observer in observers.iter_mut() {
+            observer.on_start();
+            observer.on_iteration(0, std::slice::from_ref(&current));
+        }
+
+        for iteration in 1..=self.iterations {
+            let mut candidate = current.clone();
+            for gene in candidate.genes_mut().iter_mut() {
+                *gene += sigma * standard_normal(&mut rng);
+            }
+            self.handling.repair(candidate.genes_mut(), &self.bounds, &mut rng);
+
+            let candidate_score = candidate.fitness().to_f64();
+            archive.add(candidate.clone());
+            if candidate_score <= score {
+                if candidate_score < score {
+                    successes += 1;
+                }
+                current = candidate;
+                score = candidate_score;
+            }
+
+            if iteration % self.window == 0 {
+                if successes as f64 / self.window as f64 > 0.2 {
+                    sigma /= self.factor;
+                } else {
+                    sigma *= self.factor;
+                }
+                successes = 0;
+            }
+
+            for observer in observers.iter_mut() {
+                observer.on_iteration(iteration, std::slice::from_ref(&current));
+                observer.on_metric(iteration, "sigma", sigma);
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
+
+/// Which individuals compete for survival.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurvivorSelection {
+    /// `(mu + lambda)`: parents and offspring together.
+    Plus,
+    /// `(mu, lambda)`: offspring only; parents live for one generation.
+    Comma,
+}
+
+/// Step-size parameters carried by each individual.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StepSizes {
+    /// One step size, mutated with learning rate `1 / sqrt(n)`.
+    Isotropic,
+    /// One step size per coordinate, mutated with the global and local
+    /// learning rates `1 / sqrt(2n)` and `1 / sqrt(2 sqrt(n))`.
+    PerCoordinate,
+}
+
+/// `(mu/rho +, lambda)`-ES with self-adaptive step sizes. Each child
+/// recombines `rho` random parents by averaging their genes and step sizes,
+/// mutates its step sizes log-normally and then its genes with them.
+///
+/// Observers receive the surviving population each generation and the mean
+/// step size as the `sigma` metric.
+pub struct EvolutionStrategy<I>
+where
+    I: RealVector,
+{
+    pub mu: usize,
+    pub rho: usize,
+    pub lambda: usize,
+    pub selection: SurvivorSelection,
+    pub step_sizes: StepSizes,
+    pub initial_sigma: f64,
+    pub generations: usize,
+    pub bounds: Vec<(f64, f64)>,
+    pub handling: BoundHandling,
+    pub initializer: Initializer<I>,
+}
+
+impl<I> EvolutionStrategy<I>
+where
+    I: RealVector + Default + 'static,
+{
+    pub fn new(mu: usize, lambda: usize, generations: usize, bounds: Vec<(f64, f64)>) -> Self {
+        let initializer = Initializer::sampled(UniformSampler, bounds.clone());
+        Self::from_initializer(initializer, mu, lambda, generations, bounds)
+    }
+}
+
+impl<I> EvolutionStrategy<I>
+where
+    I: RealVector,
+{
+    /// A `(mu/2, lambda)`-ES with per-coordinate step sizes starting at a
+    /// tenth of the mean bound width.
+    pub fn from_initializer(
+        initializer: Initializer<I>,
+        mu: usize,
+        lambda: usize,
+        generations: usize,
+        bounds: Vec<(f64, f64)>,
+    ) -> Self {
+        assert!(mu >= 1, "an evolution strategy needs at least one parent");
+        let width = bounds.iter().map(|(low, high)| high - low).sum::<f64>() / bounds.len().max(1) as f64;
+        Self {
+            mu,
+            rho: 2,
+            lambda,
+            selection: SurvivorSelection::Comma,
+            step_sizes: StepSizes::PerCoordinate,
+            initial_sigma: width / 10.0,
+            generations,
+            bounds,
+            handling: BoundHandling::Clip,
+            initializer,
+        }
+    }
+
+    pub fn with_selection(mut self, selection: SurvivorSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    pub fn with_step_sizes(mut self, step_sizes: StepSizes, initial_sigma: f64) -> Self {
+        self.step_sizes = step_sizes;
+        self.initial_sigma = initial_sigma;
+        self
+    }
+
+    /// Number of parents recombined into each child; 1 disables recombination.
+    pub fn with_recombination(mut self, rho: usize) -> Self {
+        self.rho = rho.max(1);
+        self
+    }
+
+    pub fn with_bound_handling(mut self, handling: BoundHandling) -> Self {
+        self.handling = handling;
+        self
+    }
+
+    fn child(&self, parents: &[Member<I>], rng: &mut ThreadRng) -> Member<I> {
+        let chosen: Vec<&Member<I>> = (0..self.rho.min(parents.len()))
+            .map(|_| &parents[rng.gen_range(0..parents.len())])
+            .collect();
+        let count = chosen.len() as f64;
+        let average = |values: &dyn Fn(&Member<I>) -> &[f64], index: usize| {
+            chosen.iter().map(|member| values(member)[index]).sum::<f64>() / count
+        };
+
+        let dimensions = chosen[0].individual.genes().len();
+        let mut genes: Vec<f64> = (0..dimensions).map(|i| average(&|m| m.individual.genes(), i)).collect();
+        let mut sigmas: Vec<f64> = (0..chosen[0].sigmas.len()).map(|i| average(&|m| &m.sigmas, i)).collect();
+
+        let n = dimensions.max(1) as f64;
+        match self.step_sizes {
+            StepSizes::Isotropic => {
+                sigmas[0] *= (standard_normal(rng) / n.sqrt()).exp();
+            }
+            StepSizes::PerCoordinate => {
+                let global = standard_normal(rng) / (2.0 * n).sqrt();
+                let local = 1.0 / (2.0 * n.sqrt()).sqrt();
+                for sigma in sigmas.iter_mut() {
+                    *sigma *= (global + local * standard_normal(rng)).exp();
+                }
+            }
+        }
+
+        for (i, gene) in genes.iter_mut().enumerate() {
+            let sigma = if sigmas.len() == 1 { sigmas[0] } else { sigmas[i] };
+            *gene += sigma * standard_normal(rng);
+        }
+        self.handling.repair(&mut genes, &self.bounds, rng);
+
+        let mut individual = chosen[0].individual.clone();
+        *individual.genes_mut() = genes;
+        Member::new(individual, sigmas)
+    }
+}
+
+struct Member<I> {
+    individual: I,
+    sigmas: Vec<f64>,
+    score: f64,
+}
+
+impl<I> Member<I>
+where
+    I: RealVector,
+{
+    fn new(individual: I, sigmas: Vec<f64>) -> Self {
+        let score = individual.fitness().to_f64();
+        Self { individual, sigmas, score }
+    }
+}
+
+impl<I> Optimizer<I> for EvolutionStrategy<I>
+where
+    I: RealVector,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        assert!(self.mu >= 1, "an evolution strategy needs at least one parent");
+        assert!(
+            self.selection == SurvivorSelection::Plus || self.lambda >= self.mu,
+            "a comma strategy needs at least mu offspring"
+        );
+
+        let mut rng = thread_rng();
+        let mut parents: Vec<Member<I>> = self
+            .initializer
+            .population(self.mu, &mut rng)
+            .into_iter()
+            .map(|individual| {
+                let count = match self.step_sizes {
+                    StepSizes::Isotropic => 1,
+                    StepSizes::PerCoordinate => individual.genes().len(),
+                };
+                Member::new(individual, vec![self.initial_sigma; count])
+            })
+            .collect();
+
+        let population: Vec<I> = parents.iter().map(|member| member.individual.clone()).collect();
+        for individual in &population {
+            archive.add(individual.clone());
+        }
+        for observer in observers.iter_mut() {
+            observer.on_start();
+            observer.on_iteration(0, &population);
+        }
+
+        for generation in 1..=self.generations {
+            let mut offspring: Vec<Member<I>> = (0..self.lambda).map(|_| self.child(&parents, &mut rng)).collect();
+            for member in &offspring {
+                archive.add(member.individual.clone());
+            }
+
+            if self.selection == SurvivorSelection::Plus {
+                offspring.append(&mut parents);
+            }
+            offspring.sort_by(|a, b| a.score.partial_cmp(&b.score).unwrap());
+            offspring.truncate(self.mu);
+            parents = offspring;
+
+            let population: Vec<I> = parents.iter().map(|member| member.individual.clone()).collect();
+            let sigmas: Vec<f64> = parents.iter().flat_map(|member| member.sigmas.iter().cloned()).collect();
+            let mean_sigma = sigmas.iter().sum::<f64>() / sigmas.len().max(1) as f64;
+            for observer in observers.iter_mut() {
+                observer.on_iteration(generation, &population);
+                observer.on_metric(generation, "sigma", mean_sigma);
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
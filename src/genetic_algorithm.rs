@@ -3,113 +3,677 @@
 use rand::prelude::*;
 use crate::optimizer::Optimizer;
 use crate::archive::Archive;
-use crate::individual::{Individual, Crossover, Mutate, FitnessValue};
+use crate::individual::{Individual, Crossover, Mutate, FitnessValue, CrossoverOperator, MutationOperator};
 use crate::observer::Observer;
+use crate::selection::{Selection, RouletteWheel};
+use crate::initializer::Initializer;
+use crate::rate_control::{RateControl, Rates, AdaptiveRates, RateAdaptation, SelfAdaptation};
+use crate::niching::{Niching, NichingMethod};
+use crate::local_search::LocalSearch;
 
 
-pub struct GeneticAlgorithm {
+pub struct GeneticAlgorithm<I>
+where
+    I: Individual,
+{
     pub population_size: usize,
     pub mutation_rate: f64,
     pub crossover_rate: f64,
     pub generations: usize,
+    pub selection: Box<dyn Selection>,
+    pub model: GenerationModel,
+    pub initializer: Initializer<I>,
+    pub crossover_operator: Option<Box<dyn CrossoverOperator<I>>>,
+    pub mutation_operator: Option<Box<dyn MutationOperator<I>>>,
+    pub mutation_control: RateControl,
+    pub crossover_control: RateControl,
+    /// When set, each individual's own rates are used and evolved instead of
+    /// the algorithm-wide ones.
+    pub self_adaptation: Option<Box<dyn RateAdaptation<I>>>,
+    /// Niching used by the generational model; the steady-state model ignores it.
+    pub niching: Option<Niching<I>>,
+    /// Local search applied to each offspring, also when the GA runs inside
+    /// an `IslandModel` or `NoveltySearch`.
+    pub local_search: Option<LocalSearch<I>>,
 }
 
-impl GeneticAlgorithm {
+impl<I> GeneticAlgorithm<I>
+where
+    I: Individual + Default,
+{
     pub fn new(
         population_size: usize,
         mutation_rate: f64,
         crossover_rate: f64,
         generations: usize,
+    ) -> Self {
+        Self::from_initializer(
+            Initializer::default(),
+            population_size,
+            mutation_rate,
+            crossover_rate,
+            generations,
+        )
+    }
+}
+
+impl<I> GeneticAlgorithm<I>
+where
+    I: Individual,
+{
+    /// Creates a GA whose initial population comes from `initializer` instead
+    /// of `I::default()`.
+    pub fn from_initializer(
+        initializer: Initializer<I>,
+        population_size: usize,
+        mutation_rate: f64,
+        crossover_rate: f64,
+        generations: usize,
     ) -> Self {
         Self {
             population_size,
             mutation_rate,
             crossover_rate,
             generations,
+            selection: Box::new(RouletteWheel),
+            model: GenerationModel::Generational,
+            initializer,
+            crossover_operator: None,
+            mutation_operator: None,
+            mutation_control: RateControl::Fixed,
+            crossover_control: RateControl::Fixed,
+            self_adaptation: None,
+            niching: None,
+            local_search: None,
         }
     }
 
-    fn initialize_population<I>(&self) -> Vec<I>
+    /// Replaces the default roulette-wheel parent selection.
+    pub fn with_selection<S>(mut self, selection: S) -> Self
     where
-        I: Individual + Default,
+        S: Selection + 'static,
     {
-        (0..self.population_size)
-            .map(|_| I::default())
-            .collect()
+        self.selection = Box::new(selection);
+        self
     }
 
-    fn selection<I>(&self, population: &Vec<I>, fitness_scores: &Vec<I::Fitness>) -> Vec<I>
+    /// Recombines parents with `operator` instead of the individual's
+    /// `Crossover` implementation.
+    pub fn with_crossover_operator<C>(mut self, operator: C) -> Self
     where
-        I: Individual,
-        I::Fitness: FitnessValue,
+        C: CrossoverOperator<I> + 'static,
     {
-        let total_fitness: f64 = fitness_scores
-            .iter()
-            .map(|f| f.to_f64())
-            .sum();
+        self.crossover_operator = Some(Box::new(operator));
+        self
+    }
 
-        let mut selected = Vec::with_capacity(self.population_size);
-        let mut rng = thread_rng();
-        for _ in 0..self.population_size {
-            let pick = rng.gen::<f64>() * total_fitness;
-            let mut current = 0.0;
-
-            for (individual, fitness) in population.iter().zip(fitness_scores.iter()) {
-                current += fitness.to_f64();
-                if current >= pick {
-                    selected.push(individual.clone());
-                    break;
-                }
+    /// Mutates children with `operator` instead of the individual's `Mutate`
+    /// implementation. `mutation_rate` still decides whether a child is
+    /// mutated at all; set it to 1.0 to leave that to the operator's per-gene
+    /// probability.
+    pub fn with_mutation_operator<M>(mut self, operator: M) -> Self
+    where
+        M: MutationOperator<I> + 'static,
+    {
+        self.mutation_operator = Some(Box::new(operator));
+        self
+    }
+
+    /// Varies `mutation_rate` during the run according to `control`.
+    pub fn with_mutation_control(mut self, control: RateControl) -> Self {
+        self.mutation_control = control;
+        self
+    }
+
+    /// Varies `crossover_rate` during the run according to `control`.
+    pub fn with_crossover_control(mut self, control: RateControl) -> Self {
+        self.crossover_control = control;
+        self
+    }
+
+    /// Evolves the rates carried by the individuals with `adaptation` instead
+    /// of the algorithm-wide ones.
+    pub fn with_rate_adaptation<R>(mut self, adaptation: R) -> Self
+    where
+        R: RateAdaptation<I> + 'static,
+    {
+        self.self_adaptation = Some(Box::new(adaptation));
+        self
+    }
+
+    /// Keeps the population spread over several optima with `niching`.
+    pub fn with_niching(mut self, niching: Niching<I>) -> Self {
+        self.niching = Some(niching);
+        self
+    }
+
+    /// Makes the GA memetic: offspring are improved by `local_search` before
+    /// competing for survival.
+    pub fn with_local_search(mut self, local_search: LocalSearch<I>) -> Self {
+        self.local_search = Some(local_search);
+        self
+    }
+
+    /// Switches to a steady-state model that breeds `offspring` children at a
+    /// time and inserts them according to `replacement`.
+    pub fn with_steady_state(mut self, offspring: usize, replacement: Replacement) -> Self {
+        self.model = GenerationModel::SteadyState { offspring, replacement };
+        self
+    }
+
+    pub(crate) fn initialize_population(&self, rng: &mut ThreadRng) -> Vec<I> {
+        self.initializer.population(self.population_size, rng)
+    }
+
+    /// Applies the local search, if any, to an offspring and returns the
+    /// genome to keep with the score it competes with.
+    fn learn(&self, child: I, rng: &mut ThreadRng) -> (I, f64) {
+        match &self.local_search {
+            Some(local_search) => local_search.learn(child, rng),
+            None => {
+                let score = child.fitness().to_f64();
+                (child, score)
             }
         }
-        selected
     }
 
-    fn crossover_and_mutate<I>(
-        &self,
-        mating_pool: Vec<I>,
-        rng: &mut ThreadRng,
-    ) -> Vec<I>
+    /// Draws the indices of a mating pool of `population_size` individuals;
+    /// lower scores are better.
+    pub(crate) fn select_mating_pool(&self, scores: &[f64], rng: &mut ThreadRng) -> Vec<usize> {
+        self.selection.select(scores, self.population_size, rng)
+    }
+
+    /// Fraction of the run completed once `generation` has been evaluated.
+    pub(crate) fn progress(&self, generation: usize) -> f64 {
+        generation as f64 / self.generations.max(1) as f64
+    }
+
+    pub(crate) fn initial_rates(&self) -> Rates {
+        Rates::new(self.mutation_rate, self.crossover_rate)
+    }
+
+    /// Rates for the next breeding step given the previous generation's
+    /// offspring success share.
+    pub(crate) fn next_rates(&self, rates: Rates, progress: f64, success: Option<f64>) -> Rates {
+        Rates::new(
+            self.mutation_control.next(self.mutation_rate, rates.mutation, progress, success),
+            self.crossover_control.next(self.crossover_rate, rates.crossover, progress, success),
+        )
+    }
+
+    fn tracks_success(&self) -> bool {
+        self.self_adaptation.is_none()
+            && (self.mutation_control.needs_success() || self.crossover_control.needs_success())
+    }
+
+    /// The rates in effect for `population`; with self-adaptation these are
+    /// the population means.
+    pub(crate) fn effective_rates(&self, rates: Rates, population: &[I]) -> Rates {
+        match &self.self_adaptation {
+            Some(adaptation) if !population.is_empty() => {
+                let count = population.len() as f64;
+                let (mutation, crossover) = population.iter().fold((0.0, 0.0), |(m, c), ind| {
+                    let rates = adaptation.rates(ind);
+                    (m + rates.mutation, c + rates.crossover)
+                });
+                Rates::new(mutation / count, crossover / count)
+            }
+            _ => rates,
+        }
+    }
+
+    /// Reports the rates in effect to observers.
+    pub(crate) fn report_rates<O>(&self, generation: usize, rates: Rates, observers: &mut [O])
     where
-        I: Individual + Crossover + Mutate,
+        O: Observer<I>,
     {
-        let mut new_population = Vec::with_capacity(self.population_size);
+        for observer in observers.iter_mut() {
+            observer.on_metric(generation, "mutation_rate", rates.mutation);
+            observer.on_metric(generation, "crossover_rate", rates.crossover);
+        }
+    }
+}
+
+/// A population being evolved by a `GeneticAlgorithm`, together with the
+/// state the generation models and rate controls carry between generations.
+pub(crate) struct Breeding<I> {
+    pub population: Vec<I>,
+    /// The scores the members compete with.
+    pub scores: Vec<f64>,
+    rates: Rates,
+    success: Option<f64>,
+    births: Vec<usize>,
+    birth: usize,
+}
+
+impl<I> Breeding<I> {
+    /// Puts `individual` with `score` in place of the member at `index`, as
+    /// the newest member of the population.
+    pub fn replace(&mut self, index: usize, individual: I, score: f64) {
+        self.birth += 1;
+        self.population[index] = individual;
+        self.scores[index] = score;
+        self.births[index] = self.birth;
+    }
+}
+
+impl<I> GeneticAlgorithm<I>
+where
+    I: AdaptiveRates,
+{
+    /// Evolves the rates stored in each individual alongside its genome,
+    /// using a logit-normal perturbation with the given `learning_rate`.
+    /// `mutation_rate`, `crossover_rate` and their controls are then unused.
+    pub fn with_self_adaptation(self, learning_rate: f64) -> Self {
+        self.with_rate_adaptation(SelfAdaptation::new(learning_rate))
+    }
+}
+
+impl<I> GeneticAlgorithm<I>
+where
+    I: Individual + Crossover + Mutate,
+    I::Fitness: FitnessValue,
+{
+    /// Breeds a new population from the members of `population` at the
+    /// indices in `pool` and passes each child through the local search.
+    /// Returns the offspring, the scores they compete with and, if a rate
+    /// control needs it, the share that beat their parent's score in `scores`.
+    pub(crate) fn crossover_and_mutate(
+        &self,
+        population: &[I],
+        scores: &[f64],
+        pool: Vec<usize>,
+        rates: Rates,
+        progress: f64,
+        rng: &mut ThreadRng,
+    ) -> (Vec<I>, Vec<f64>, Option<f64>) {
+        let pool_scores: Vec<f64> = pool.iter().map(|&index| scores[index]).collect();
+        let mating_pool: Vec<I> = pool.into_iter().map(|index| population[index].clone()).collect();
+        let mut offspring = Vec::with_capacity(self.population_size);
+        let mut offspring_scores = Vec::with_capacity(self.population_size);
+        let mut successes = 0;
 
         for _ in 0..(self.population_size / 2) {
-            let parent1 = &mating_pool[rng.gen_range(0..self.population_size)];
-            let parent2 = &mating_pool[rng.gen_range(0..self.population_size)];
+            let first = rng.gen_range(0..mating_pool.len());
+            let second = rng.gen_range(0..mating_pool.len());
 
-            let mut child1 = if rng.gen::<f64>() < self.crossover_rate {
-                parent1.crossover(parent2, rng)
-            } else {
-                parent1.clone()
-            };
+            let (child1, child2) = self.breed(&mating_pool[first], &mating_pool[second], &mating_pool, rates, progress, rng);
+
+            for (child, parent) in [(child1, first), (child2, second)] {
+                let (child, score) = self.learn(child, rng);
+                successes += (score < pool_scores[parent]) as usize;
+                offspring.push(child);
+                offspring_scores.push(score);
+            }
+        }
 
-            let mut child2 = if rng.gen::<f64>() < self.crossover_rate {
-                parent2.crossover(parent1, rng)
+        let success = self.tracks_success().then(|| successes as f64 / offspring.len().max(1) as f64);
+        (offspring, offspring_scores, success)
+    }
+
+    /// Produces two children, the first derived from `parent1` and the second
+    /// from `parent2`. Crossover operators needing more than two parents draw
+    /// the extra ones from `pool`. `progress` is the fraction of the run completed.
+    pub(crate) fn breed(
+        &self,
+        parent1: &I,
+        parent2: &I,
+        pool: &[I],
+        rates: Rates,
+        progress: f64,
+        rng: &mut ThreadRng,
+    ) -> (I, I) {
+        let child1 = self.offspring(parent1, parent2, pool, rates, progress, rng);
+        let child2 = self.offspring(parent2, parent1, pool, rates, progress, rng);
+        (child1, child2)
+    }
+
+    fn offspring(&self, parent: &I, mate: &I, pool: &[I], rates: Rates, progress: f64, rng: &mut ThreadRng) -> I {
+        let (own, other) = match &self.self_adaptation {
+            Some(adaptation) => (adaptation.rates(parent), adaptation.rates(mate)),
+            None => (rates, rates),
+        };
+
+        let crossed = rng.gen::<f64>() < own.crossover;
+        let mut child = if crossed {
+            self.crossover(parent, mate, pool, rng)
+        } else {
+            parent.clone()
+        };
+
+        let mut child_rates = if crossed { own.mean(&other) } else { own };
+        if let Some(adaptation) = &self.self_adaptation {
+            child_rates = adaptation.perturb(child_rates, rng);
+            adaptation.set_rates(&mut child, child_rates);
+        }
+
+        if rng.gen::<f64>() < child_rates.mutation {
+            self.mutate(&mut child, progress, rng);
+        }
+
+        child
+    }
+
+    fn mutate(&self, individual: &mut I, progress: f64, rng: &mut ThreadRng) {
+        match &self.mutation_operator {
+            Some(operator) => operator.mutate(individual, progress, rng),
+            None => individual.mutate(rng),
+        }
+    }
+
+    fn crossover(&self, parent: &I, mate: &I, pool: &[I], rng: &mut ThreadRng) -> I {
+        match &self.crossover_operator {
+            Some(operator) => {
+                let mut parents = vec![parent, mate];
+                while parents.len() < operator.parents() {
+                    parents.push(&pool[rng.gen_range(0..pool.len())]);
+                }
+                operator.crossover(&parents, rng)
+            }
+            None => parent.crossover(mate, rng),
+        }
+    }
+
+    /// Deterministic crowding: random parent pairs breed, and each child
+    /// replaces the closer parent if it is at least as good.
+    fn crowd(
+        &self,
+        niching: &Niching<I>,
+        mut population: Vec<I>,
+        mut scores: Vec<f64>,
+        rates: Rates,
+        progress: f64,
+        rng: &mut ThreadRng,
+    ) -> (Vec<I>, Vec<f64>) {
+        let mut order: Vec<usize> = (0..population.len()).collect();
+        order.shuffle(rng);
+
+        for pair in order.chunks_exact(2) {
+            let (a, b) = (pair[0], pair[1]);
+            let (child1, child2) = self.breed(&population[a], &population[b], &population, rates, progress, rng);
+            let (child1, score1) = self.learn(child1, rng);
+            let (child2, score2) = self.learn(child2, rng);
+
+            let straight = niching.distance(&population[a], &child1) + niching.distance(&population[b], &child2);
+            let crossed = niching.distance(&population[a], &child2) + niching.distance(&population[b], &child1);
+            let matches = if straight <= crossed {
+                [(a, child1, score1), (b, child2, score2)]
             } else {
-                parent2.clone()
+                [(a, child2, score2), (b, child1, score1)]
             };
 
-            if rng.gen::<f64>() < self.mutation_rate {
-                child1.mutate(rng);
+            for (parent, child, score) in matches {
+                if score <= scores[parent] {
+                    population[parent] = child;
+                    scores[parent] = score;
+                }
+            }
+        }
+
+        (population, scores)
+    }
+
+    /// Restricted tournament selection: random parents breed and each child
+    /// replaces the closest member of a random window if it is better.
+    fn restricted_tournament(
+        &self,
+        niching: &Niching<I>,
+        mut population: Vec<I>,
+        mut scores: Vec<f64>,
+        rates: Rates,
+        progress: f64,
+        rng: &mut ThreadRng,
+    ) -> (Vec<I>, Vec<f64>) {
+        let NichingMethod::RestrictedTournament { window } = niching.method else {
+            return (population, scores);
+        };
+
+        for _ in 0..population.len() / 2 {
+            let a = rng.gen_range(0..population.len());
+            let b = rng.gen_range(0..population.len());
+            let (child1, child2) = self.breed(&population[a], &population[b], &population, rates, progress, rng);
+
+            for child in [child1, child2] {
+                let (child, score) = self.learn(child, rng);
+                let closest = niching.closest_in_window(&child, &population, window, rng);
+                if score < scores[closest] {
+                    population[closest] = child;
+                    scores[closest] = score;
+                }
+            }
+        }
+
+        (population, scores)
+    }
+
+    pub(crate) fn start_breeding(&self, population: Vec<I>) -> Breeding<I> {
+        let scores = population.iter().map(|ind| ind.fitness().to_f64()).collect();
+        Breeding {
+            births: vec![0; population.len()],
+            population,
+            scores,
+            rates: self.initial_rates(),
+            success: None,
+            birth: 0,
+        }
+    }
+
+    /// Breeds generation `generation` with the configured generation model and
+    /// niching, and returns the rates in effect for it. Steady-state children
+    /// are passed to `on_birth` as they are evaluated; generational offspring
+    /// simply become the new population.
+    pub(crate) fn breed_generation<F>(
+        &self,
+        state: &mut Breeding<I>,
+        generation: usize,
+        rng: &mut ThreadRng,
+        on_birth: F,
+    ) -> Rates
+    where
+        F: FnMut(&I),
+    {
+        let progress = self.progress(generation);
+        state.rates = self.next_rates(state.rates, progress, state.success);
+        let rates = self.effective_rates(state.rates, &state.population);
+
+        match self.model {
+            GenerationModel::Generational => self.generational_step(state, progress, rng),
+            GenerationModel::SteadyState { offspring, replacement } => {
+                self.steady_state_step(state, offspring, replacement, progress, rng, on_birth)
+            }
+        }
+
+        rates
+    }
+
+    fn generational_step(&self, state: &mut Breeding<I>, progress: f64, rng: &mut ThreadRng) {
+        let population = std::mem::take(&mut state.population);
+        let scores = std::mem::take(&mut state.scores);
+
+        (state.population, state.scores) = match &self.niching {
+            Some(niching) if matches!(niching.method, NichingMethod::DeterministicCrowding) => {
+                state.success = None;
+                self.crowd(niching, population, scores, state.rates, progress, rng)
+            }
+            Some(niching) if matches!(niching.method, NichingMethod::RestrictedTournament { .. }) => {
+                state.success = None;
+                self.restricted_tournament(niching, population, scores, state.rates, progress, rng)
+            }
+            _ => {
+                let pool = match &self.niching {
+                    Some(niching) => self.select_mating_pool(&niching.adjust_scores(&population, &scores), rng),
+                    None => self.select_mating_pool(&scores, rng),
+                };
+                let (offspring, offspring_scores, success) =
+                    self.crossover_and_mutate(&population, &scores, pool, state.rates, progress, rng);
+                state.success = success;
+                (offspring, offspring_scores)
+            }
+        };
+    }
+
+    /// Performs as many breeding steps as it takes to produce as many
+    /// offspring as there are members, so the evaluation budget matches the
+    /// generational model.
+    fn steady_state_step<F>(
+        &self,
+        state: &mut Breeding<I>,
+        offspring: usize,
+        replacement: Replacement,
+        progress: f64,
+        rng: &mut ThreadRng,
+        mut on_birth: F,
+    )
+    where
+        F: FnMut(&I),
+    {
+        let offspring = offspring.max(1);
+        let steps = state.population.len().div_ceil(offspring);
+        let mut successes = 0;
+        let mut births = 0;
+
+        for _ in 0..steps {
+            let mut children = Vec::with_capacity(offspring + 1);
+            while children.len() < offspring {
+                let parents = self.selection.select(&state.scores, 2, rng);
+                let (child1, child2) = self.breed(
+                    &state.population[parents[0]],
+                    &state.population[parents[1]],
+                    &state.population,
+                    state.rates,
+                    progress,
+                    rng,
+                );
+                children.push((child1, parents[0]));
+                children.push((child2, parents[1]));
+            }
+            children.truncate(offspring);
+
+            for (child, parent) in children {
+                let (child, score) = self.learn(child, rng);
+                on_birth(&child);
+                births += 1;
+                if score < state.scores[parent] {
+                    successes += 1;
+                }
+
+                if let Some(index) = replacement.victim(&state.scores, &state.births, parent, score, rng) {
+                    state.replace(index, child, score);
+                }
+            }
+        }
+
+        state.success = Some(successes as f64 / births.max(1) as f64);
+    }
+
+    fn run_generational<A, O>(&self, population: Vec<I>, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut rng = thread_rng();
+        let mut state = self.start_breeding(population);
+
+        for generation in 1..=self.generations {
+            for individual in &state.population {
+                archive.add(individual.clone());
             }
 
-            if rng.gen::<f64>() < self.mutation_rate {
-                child2.mutate(rng);
+            for observer in observers.iter_mut() {
+                observer.on_iteration(generation, &state.population);
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
             }
 
-            new_population.push(child1);
-            new_population.push(child2);
+            let rates = self.breed_generation(&mut state, generation, &mut rng, |_| {});
+            self.report_rates(generation, rates, observers);
         }
+    }
 
-        new_population
+    fn run_steady_state<A, O>(&self, population: Vec<I>, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut rng = thread_rng();
+
+        for individual in &population {
+            archive.add(individual.clone());
+        }
+        let mut state = self.start_breeding(population);
+
+        for generation in 1..=self.generations {
+            let rates = self.breed_generation(&mut state, generation, &mut rng, |child| archive.add(child.clone()));
+            self.report_rates(generation, rates, observers);
+
+            for observer in observers.iter_mut() {
+                observer.on_iteration(generation, &state.population);
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+        }
     }
 }
 
-impl<I> Optimizer<I> for GeneticAlgorithm
+/// How the population is renewed each generation.
+#[derive(Clone, Copy, Debug)]
+pub enum GenerationModel {
+    /// The whole population is replaced by offspring every generation.
+    Generational,
+    /// `offspring` children are bred at a time and inserted one by one
+    /// according to `replacement`.
+    SteadyState {
+        offspring: usize,
+        replacement: Replacement,
+    },
+}
+
+/// Replacement policy deciding which member of the population a new child
+/// displaces in steady-state mode.
+#[derive(Clone, Copy, Debug)]
+pub enum Replacement {
+    /// Replace the individual with the worst fitness.
+    Worst,
+    /// Replace the individual that has been in the population longest.
+    Oldest,
+    /// Replace the loser of a tournament of the given size.
+    Tournament(usize),
+    /// Replace the child's parent, but only if the child is better.
+    ParentIfBetter,
+}
+
+impl Replacement {
+    fn victim(
+        &self,
+        scores: &[f64],
+        births: &[usize],
+        parent: usize,
+        child_score: f64,
+        rng: &mut ThreadRng,
+    ) -> Option<usize> {
+        match *self {
+            Replacement::Worst => (0..scores.len())
+                .max_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap()),
+            Replacement::Oldest => (0..births.len()).min_by_key(|&index| births[index]),
+            Replacement::Tournament(size) => (0..size.max(1))
+                .map(|_| rng.gen_range(0..scores.len()))
+                .max_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap()),
+            Replacement::ParentIfBetter => {
+                if child_score < scores[parent] {
+                    Some(parent)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl<I> Optimizer<I> for GeneticAlgorithm<I>
 where
-    I: Individual + Crossover + Mutate + Default,
+    I: Individual + Crossover + Mutate,
     I::Fitness: PartialOrd + FitnessValue,
 {
     fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
@@ -117,8 +681,7 @@ where
         A: Archive<Solution = I, Fitness = I::Fitness>,
         O: Observer<I>,
     {
-        let mut population: Vec<I> = self.initialize_population();
-        let mut rng = thread_rng();
+        let population: Vec<I> = self.initialize_population(&mut thread_rng());
 
         // Notify observers at the start
         for observer in observers.iter_mut() {
@@ -126,20 +689,9 @@ where
             observer.on_iteration(0, &population);
         }
 
-        for generation in 1..=self.generations {
-            let fitness_scores: Vec<I::Fitness> = population.iter().map(|ind| ind.fitness()).collect();
-
-            for individual in &population {
-                archive.add(individual.clone());
-            }
-
-            for observer in observers.iter_mut() {
-                observer.on_iteration(generation, &population);
-            }
-
-            let mating_pool = self.selection(&population, &fitness_scores);
-
-            population = self.crossover_and_mutate(mating_pool, &mut rng);
+        match self.model {
+            GenerationModel::Generational => self.run_generational(population, archive, observers),
+            GenerationModel::SteadyState { .. } => self.run_steady_state(population, archive, observers),
         }
 
         for observer in observers.iter_mut() {
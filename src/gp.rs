@@ -0,0 +1,786 @@
+use rand::prelude::*;
+use std::fmt;
+use std::sync::Arc;
+use crate::individual::{Individual, Crossover, Mutate, CrossoverOperator, MutationOperator};
+use crate::initializer::Initializer;
+
+
+/// Type of a value flowing along a tree edge.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type {
+    Float,
+    Bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Value {
+    Float(f64),
+    Bool(bool),
+}
+
+impl Value {
+    pub fn value_type(&self) -> Type {
+        match self {
+            Value::Float(_) => Type::Float,
+            Value::Bool(_) => Type::Bool,
+        }
+    }
+
+    pub fn as_f64(&self) -> f64 {
+        match *self {
+            Value::Float(value) => value,
+            Value::Bool(value) => value as u8 as f64,
+        }
+    }
+
+    pub fn as_bool(&self) -> bool {
+        match *self {
+            Value::Float(value) => value != 0.0,
+            Value::Bool(value) => value,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Float(value) => write!(f, "{}", value),
+            Value::Bool(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+pub type Operation = Arc<dyn Fn(&[Value]) -> Value + Send + Sync>;
+pub type EphemeralGenerator = Arc<dyn Fn(&mut ThreadRng) -> Value + Send + Sync>;
+pub type TreeFitness = Arc<dyn Fn(&Tree, &PrimitiveSet) -> f64 + Send + Sync>;
+
+/// An inner node: takes `arg_types` and returns `return_type`.
+#[derive(Clone)]
+pub struct Function {
+    pub name: String,
+    pub arg_types: Vec<Type>,
+    pub return_type: Type,
+    pub operation: Operation,
+}
+
+#[derive(Clone)]
+pub enum TerminalKind {
+    /// The input at this index; always a float.
+    Variable(usize),
+    Constant(Value),
+    /// A constant drawn once when the terminal is placed in a tree.
+    Ephemeral(EphemeralGenerator),
+}
+
+#[derive(Clone)]
+pub struct Terminal {
+    pub name: String,
+    pub return_type: Type,
+    pub kind: TerminalKind,
+}
+
+/// The function and terminal sets a program is built from.
+#[derive(Clone)]
+pub struct PrimitiveSet {
+    pub functions: Vec<Function>,
+    pub terminals: Vec<Terminal>,
+    /// Type returned by whole programs.
+    pub root_type: Type,
+    variables: usize,
+}
+
+impl PrimitiveSet {
+    pub fn new(root_type: Type) -> Self {
+        Self {
+            functions: Vec::new(),
+            terminals: Vec::new(),
+            root_type,
+            variables: 0,
+        }
+    }
+
+    /// Float-valued primitives for symbolic regression: `+`, `-`, `*`,
+    /// protected `/` (returning 1 for a zero divisor), the inputs `x0..xn` and
+    /// an ephemeral constant from `[-1, 1)`.
+    pub fn arithmetic(variables: usize) -> Self {
+        let mut primitives = Self::new(Type::Float);
+        primitives
+            .add_float_function("+", 2, |args| args[0] + args[1])
+            .add_float_function("-", 2, |args| args[0] - args[1])
+            .add_float_function("*", 2, |args| args[0] * args[1])
+            .add_float_function("/", 2, |args| if args[1].abs() < 1e-9 { 1.0 } else { args[0] / args[1] });
+        for index in 0..variables {
+            primitives.add_variable(&format!("x{}", index));
+        }
+        primitives.add_ephemeral("const", Type::Float, |rng| Value::Float(rng.gen_range(-1.0..1.0)));
+        primitives
+    }
+
+    pub fn add_function<F>(&mut self, name: &str, arg_types: Vec<Type>, return_type: Type, operation: F) -> &mut Self
+    where
+        F: Fn(&[Value]) -> Value + Send + Sync + 'static,
+    {
+        self.functions.push(Function {
+            name: name.to_string(),
+            arg_types,
+            return_type,
+            operation: Arc::new(operation),
+        });
+        self
+    }
+
+    /// Adds a function from `arity` floats to a float.
+    pub fn add_float_function<F>(&mut self, name: &str, arity: usize, operation: F) -> &mut Self
+    where
+        F: Fn(&[f64]) -> f64 + Send + Sync + 'static,
+    {
+        self.add_function(name, vec![Type::Float; arity], Type::Float, move |args| {
+            let args: Vec<f64> = args.iter().map(Value::as_f64).collect();
+            Value::Float(operation(&args))
+        })
+    }
+
+    /// Adds the next program input as a float terminal.
+    pub fn add_variable(&mut self, name: &str) -> &mut Self {
+        self.terminals.push(Terminal {
+            name: name.to_string(),
+            return_type: Type::Float,
+            kind: TerminalKind::Variable(self.variables),
+        });
+        self.variables += 1;
+        self
+    }
+
+    pub fn add_constant(&mut self, name: &str, value: Value) -> &mut Self {
+        self.terminals.push(Terminal {
+            name: name.to_string(),
+            return_type: value.value_type(),
+            kind: TerminalKind::Constant(value),
+        });
+        self
+    }
+
+    pub fn add_ephemeral<F>(&mut self, name: &str, return_type: Type, generator: F) -> &mut Self
+    where
+        F: Fn(&mut ThreadRng) -> Value + Send + Sync + 'static,
+    {
+        self.terminals.push(Terminal {
+            name: name.to_string(),
+            return_type,
+            kind: TerminalKind::Ephemeral(Arc::new(generator)),
+        });
+        self
+    }
+
+    pub fn variables(&self) -> usize {
+        self.variables
+    }
+
+    /// Depth of the shallowest complete tree returning `return_type`, or
+    /// `None` if no tree of that type can end in terminals.
+    pub fn min_depth(&self, return_type: Type) -> Option<usize> {
+        depth_of(&self.min_depths(), return_type)
+    }
+
+    /// Types that can appear in a program: the root type and every argument
+    /// type of a function returning a type that can appear.
+    pub fn reachable_types(&self) -> Vec<Type> {
+        let mut types = vec![self.root_type];
+        let mut next = 0;
+        while next < types.len() {
+            for index in self.functions_of(types[next]) {
+                for &arg_type in &self.functions[index].arg_types {
+                    if !types.contains(&arg_type) {
+                        types.push(arg_type);
+                    }
+                }
+            }
+            next += 1;
+        }
+        types
+    }
+
+    fn min_depths(&self) -> Vec<(Type, usize)> {
+        let mut depths: Vec<(Type, usize)> = Vec::new();
+        for terminal in &self.terminals {
+            if depth_of(&depths, terminal.return_type).is_none() {
+                depths.push((terminal.return_type, 0));
+            }
+        }
+        // Depths only ever shrink, so this settles.
+        loop {
+            let mut changed = false;
+            for function in &self.functions {
+                let Some(height) = height_of(function, &depths) else {
+                    continue;
+                };
+                match depths.iter_mut().find(|(known, _)| *known == function.return_type) {
+                    Some((_, depth)) if *depth <= height => {}
+                    Some((_, depth)) => {
+                        *depth = height;
+                        changed = true;
+                    }
+                    None => {
+                        depths.push((function.return_type, height));
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                return depths;
+            }
+        }
+    }
+
+    /// Functions returning `return_type` that complete a tree in as few
+    /// levels as possible.
+    fn shallowest_functions_of(&self, return_type: Type) -> Vec<usize> {
+        let depths = self.min_depths();
+        let best = depth_of(&depths, return_type);
+        self.functions_of(return_type)
+            .into_iter()
+            .filter(|&index| best.is_some() && height_of(&self.functions[index], &depths) == best)
+            .collect()
+    }
+
+    fn functions_of(&self, return_type: Type) -> Vec<usize> {
+        (0..self.functions.len())
+            .filter(|&index| self.functions[index].return_type == return_type)
+            .collect()
+    }
+
+    fn terminals_of(&self, return_type: Type) -> Vec<usize> {
+        (0..self.terminals.len())
+            .filter(|&index| self.terminals[index].return_type == return_type)
+            .collect()
+    }
+
+    fn terminal_node(&self, index: usize, rng: &mut ThreadRng) -> Node {
+        match &self.terminals[index].kind {
+            TerminalKind::Ephemeral(generator) => Node::Constant(generator(rng)),
+            _ => Node::Terminal(index),
+        }
+    }
+}
+
+fn depth_of(depths: &[(Type, usize)], return_type: Type) -> Option<usize> {
+    depths.iter().find(|(known, _)| *known == return_type).map(|&(_, depth)| depth)
+}
+
+/// Depth of the shallowest complete tree rooted at `function`.
+fn height_of(function: &Function, depths: &[(Type, usize)]) -> Option<usize> {
+    function
+        .arg_types
+        .iter()
+        .map(|&arg_type| depth_of(depths, arg_type))
+        .collect::<Option<Vec<usize>>>()
+        .map(|args| 1 + args.into_iter().max().unwrap_or(0))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Node {
+    Function(usize),
+    Terminal(usize),
+    /// The value of an ephemeral terminal.
+    Constant(Value),
+}
+
+/// An expression tree stored in prefix order; indices in nodes refer to a
+/// `PrimitiveSet`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tree {
+    pub nodes: Vec<Node>,
+}
+
+impl Tree {
+    pub fn new(nodes: Vec<Node>) -> Self {
+        Self { nodes }
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn arity(&self, index: usize, primitives: &PrimitiveSet) -> usize {
+        match self.nodes[index] {
+            Node::Function(function) => primitives.functions[function].arg_types.len(),
+            _ => 0,
+        }
+    }
+
+    pub fn node_type(&self, index: usize, primitives: &PrimitiveSet) -> Type {
+        match self.nodes[index] {
+            Node::Function(function) => primitives.functions[function].return_type,
+            Node::Terminal(terminal) => primitives.terminals[terminal].return_type,
+            Node::Constant(value) => value.value_type(),
+        }
+    }
+
+    /// One past the last node of the subtree rooted at `index`.
+    pub fn subtree_end(&self, index: usize, primitives: &PrimitiveSet) -> usize {
+        let mut open = 1;
+        let mut end = index;
+        while open > 0 {
+            open = open + self.arity(end, primitives) - 1;
+            end += 1;
+        }
+        end
+    }
+
+    /// Depth of the tree; a single terminal has depth 0.
+    pub fn depth(&self, primitives: &PrimitiveSet) -> usize {
+        let mut remaining: Vec<usize> = Vec::new();
+        let mut depth = 0;
+        for index in 0..self.nodes.len() {
+            depth = depth.max(remaining.len());
+            let arity = self.arity(index, primitives);
+            if arity > 0 {
+                remaining.push(arity);
+                continue;
+            }
+            while let Some(top) = remaining.last_mut() {
+                *top -= 1;
+                if *top > 0 {
+                    break;
+                }
+                remaining.pop();
+            }
+        }
+        depth
+    }
+
+    pub fn subtree(&self, index: usize, primitives: &PrimitiveSet) -> Tree {
+        Tree::new(self.nodes[index..self.subtree_end(index, primitives)].to_vec())
+    }
+
+    /// Returns a copy with the subtree at `index` replaced by `replacement`.
+    pub fn replace(&self, index: usize, replacement: &[Node], primitives: &PrimitiveSet) -> Tree {
+        let end = self.subtree_end(index, primitives);
+        let mut nodes = Vec::with_capacity(self.nodes.len() - (end - index) + replacement.len());
+        nodes.extend_from_slice(&self.nodes[..index]);
+        nodes.extend_from_slice(replacement);
+        nodes.extend_from_slice(&self.nodes[end..]);
+        Tree::new(nodes)
+    }
+
+    pub fn evaluate(&self, primitives: &PrimitiveSet, inputs: &[f64]) -> Value {
+        let mut position = 0;
+        self.evaluate_from(primitives, inputs, &mut position)
+    }
+
+    fn evaluate_from(&self, primitives: &PrimitiveSet, inputs: &[f64], position: &mut usize) -> Value {
+        let node = self.nodes[*position];
+        *position += 1;
+        match node {
+            Node::Function(index) => {
+                let function = &primitives.functions[index];
+                let args: Vec<Value> = (0..function.arg_types.len())
+                    .map(|_| self.evaluate_from(primitives, inputs, position))
+                    .collect();
+                (function.operation)(&args)
+            }
+            Node::Terminal(index) => match &primitives.terminals[index].kind {
+                TerminalKind::Variable(variable) => Value::Float(inputs[*variable]),
+                TerminalKind::Constant(value) => *value,
+                // Generated trees store ephemerals as constants; a hand-built
+                // tree that refers to the terminal draws a value each time.
+                TerminalKind::Ephemeral(generator) => generator(&mut thread_rng()),
+            },
+            Node::Constant(value) => value,
+        }
+    }
+
+    /// Formats the tree as an S-expression.
+    pub fn display<'a>(&'a self, primitives: &'a PrimitiveSet) -> impl fmt::Display + 'a {
+        TreeDisplay { tree: self, primitives }
+    }
+}
+
+struct TreeDisplay<'a> {
+    tree: &'a Tree,
+    primitives: &'a PrimitiveSet,
+}
+
+impl TreeDisplay<'_> {
+    fn write(&self, f: &mut fmt::Formatter<'_>, position: &mut usize) -> fmt::Result {
+        let node = self.tree.nodes[*position];
+        *position += 1;
+        match node {
+            Node::Function(index) => {
+                let function = &self.primitives.functions[index];
+                write!(f, "({}", function.name)?;
+                for _ in 0..function.arg_types.len() {
+                    write!(f, " ")?;
+                    self.write(f, position)?;
+                }
+                write!(f, ")")
+            }
+            Node::Terminal(index) => write!(f, "{}", self.primitives.terminals[index].name),
+            Node::Constant(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl fmt::Display for TreeDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.tree.is_empty() {
+            return Ok(());
+        }
+        self.write(f, &mut 0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeMutation {
+    /// Swaps one node for another primitive of the same signature.
+    Point,
+    /// Replaces a random subtree with a newly grown one.
+    Subtree,
+    /// Replaces the tree with one of its own subtrees, shrinking it.
+    Hoist,
+}
+
+/// Settings shared by all trees of a run: primitives, fitness function,
+/// initialisation depths and the limits used for bloat control.
+///
+/// Offspring deeper than `max_depth` or larger than `max_size` are discarded
+/// in favour of a copy of the parent. Fitness is the value returned by the
+/// fitness function (lower is better) plus `parsimony` times the tree size.
+pub struct GpConfig {
+    pub primitives: PrimitiveSet,
+    pub min_init_depth: usize,
+    pub max_init_depth: usize,
+    pub max_depth: usize,
+    pub max_size: usize,
+    pub parsimony: f64,
+    /// Maximum depth of subtrees grown by subtree mutation.
+    pub mutation_depth: usize,
+    /// Relative weights of the mutations used by `Mutate`.
+    pub mutations: Vec<(TreeMutation, f64)>,
+    fitness: TreeFitness,
+}
+
+impl GpConfig {
+    /// Panics if some type a program can contain has no tree that ends in
+    /// terminals, since growing such a tree would never finish.
+    pub fn new<F>(primitives: PrimitiveSet, fitness: F) -> Self
+    where
+        F: Fn(&Tree, &PrimitiveSet) -> f64 + Send + Sync + 'static,
+    {
+        for return_type in primitives.reachable_types() {
+            assert!(
+                primitives.min_depth(return_type).is_some(),
+                "no finite tree returns {:?}: it needs a terminal or a function whose arguments all have one",
+                return_type
+            );
+        }
+        Self {
+            primitives,
+            min_init_depth: 2,
+            max_init_depth: 6,
+            max_depth: 17,
+            max_size: 200,
+            parsimony: 0.0,
+            mutation_depth: 4,
+            mutations: vec![(TreeMutation::Point, 1.0), (TreeMutation::Subtree, 1.0), (TreeMutation::Hoist, 1.0)],
+            fitness: Arc::new(fitness),
+        }
+    }
+
+    pub fn with_init_depth(mut self, min: usize, max: usize) -> Self {
+        self.min_init_depth = min;
+        self.max_init_depth = max.max(min);
+        self
+    }
+
+    pub fn with_limits(mut self, max_depth: usize, max_size: usize) -> Self {
+        self.max_depth = max_depth;
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn with_parsimony(mut self, parsimony: f64) -> Self {
+        self.parsimony = parsimony;
+        self
+    }
+
+    pub fn with_mutations(mut self, mutations: Vec<(TreeMutation, f64)>) -> Self {
+        self.mutations = mutations;
+        self
+    }
+
+    /// The raw fitness of `tree`, without the parsimony penalty.
+    pub fn raw_fitness(&self, tree: &Tree) -> f64 {
+        (self.fitness)(tree, &self.primitives)
+    }
+
+    pub fn individual(self: &Arc<Self>, tree: Tree) -> TreeIndividual {
+        let raw_fitness = self.raw_fitness(&tree);
+        let fitness = raw_fitness + self.parsimony * tree.len() as f64;
+        TreeIndividual {
+            tree,
+            fitness,
+            raw_fitness,
+            config: Arc::clone(self),
+        }
+    }
+
+    /// Builds a tree of `return_type` whose branches all reach `depth` where
+    /// the primitives allow it.
+    pub fn full(&self, return_type: Type, depth: usize, rng: &mut ThreadRng) -> Tree {
+        let mut nodes = Vec::new();
+        self.generate(return_type, depth, true, rng, &mut nodes);
+        Tree::new(nodes)
+    }
+
+    /// Builds a tree of `return_type` no deeper than `depth`, choosing
+    /// between functions and terminals at random.
+    pub fn grow(&self, return_type: Type, depth: usize, rng: &mut ThreadRng) -> Tree {
+        let mut nodes = Vec::new();
+        self.generate(return_type, depth, false, rng, &mut nodes);
+        Tree::new(nodes)
+    }
+
+    /// Koza's ramped half-and-half: depths cycle through
+    /// `min_init_depth..=max_init_depth`, alternating full and grow trees.
+    pub fn ramped_half_and_half(self: &Arc<Self>, count: usize, rng: &mut ThreadRng) -> Vec<TreeIndividual> {
+        let depths = self.max_init_depth - self.min_init_depth + 1;
+        (0..count)
+            .map(|index| {
+                let depth = self.min_init_depth + (index / 2) % depths;
+                let tree = if index % 2 == 0 {
+                    self.full(self.primitives.root_type, depth, rng)
+                } else {
+                    self.grow(self.primitives.root_type, depth, rng)
+                };
+                self.individual(tree)
+            })
+            .collect()
+    }
+
+    /// An initializer producing ramped half-and-half populations.
+    pub fn initializer(self: &Arc<Self>) -> Initializer<TreeIndividual> {
+        let config = Arc::clone(self);
+        Initializer::from_batch(move |count, rng| config.ramped_half_and_half(count, rng))
+    }
+
+    pub fn within_limits(&self, tree: &Tree) -> bool {
+        tree.len() <= self.max_size && tree.depth(&self.primitives) <= self.max_depth
+    }
+
+    /// Replaces a random subtree of `tree` with a subtree of the same type
+    /// from `donor`. Returns a copy of `tree` if no such subtree exists or the
+    /// child would break the limits.
+    pub fn subtree_crossover(&self, tree: &Tree, donor: &Tree, rng: &mut ThreadRng) -> Tree {
+        let primitives = &self.primitives;
+        let point = rng.gen_range(0..tree.len());
+        let point_type = tree.node_type(point, primitives);
+        let candidates: Vec<usize> = (0..donor.len())
+            .filter(|&index| donor.node_type(index, primitives) == point_type)
+            .collect();
+
+        match candidates.choose(rng) {
+            Some(&source) => {
+                let end = donor.subtree_end(source, primitives);
+                self.limited(tree.replace(point, &donor.nodes[source..end], primitives), tree)
+            }
+            None => tree.clone(),
+        }
+    }
+
+    pub fn point_mutation(&self, tree: &Tree, rng: &mut ThreadRng) -> Tree {
+        let primitives = &self.primitives;
+        let point = rng.gen_range(0..tree.len());
+        let mut mutated = tree.clone();
+        mutated.nodes[point] = match tree.nodes[point] {
+            Node::Function(index) => {
+                let function = &primitives.functions[index];
+                let alternatives: Vec<usize> = (0..primitives.functions.len())
+                    .filter(|&other| {
+                        primitives.functions[other].return_type == function.return_type
+                            && primitives.functions[other].arg_types == function.arg_types
+                    })
+                    .collect();
+                Node::Function(*alternatives.choose(rng).unwrap())
+            }
+            _ => {
+                let terminals = primitives.terminals_of(tree.node_type(point, primitives));
+                primitives.terminal_node(*terminals.choose(rng).unwrap(), rng)
+            }
+        };
+        mutated
+    }
+
+    pub fn subtree_mutation(&self, tree: &Tree, rng: &mut ThreadRng) -> Tree {
+        let point = rng.gen_range(0..tree.len());
+        let replacement = self.grow(tree.node_type(point, &self.primitives), self.mutation_depth, rng);
+        self.limited(tree.replace(point, &replacement.nodes, &self.primitives), tree)
+    }
+
+    /// Picks a random proper subtree of the root type and makes it the new tree.
+    pub fn hoist_mutation(&self, tree: &Tree, rng: &mut ThreadRng) -> Tree {
+        let root_type = self.primitives.root_type;
+        let candidates: Vec<usize> = (1..tree.len())
+            .filter(|&index| tree.node_type(index, &self.primitives) == root_type)
+            .collect();
+        match candidates.choose(rng) {
+            Some(&point) => tree.subtree(point, &self.primitives),
+            None => tree.clone(),
+        }
+    }
+
+    pub fn mutate(&self, tree: &Tree, mutation: TreeMutation, rng: &mut ThreadRng) -> Tree {
+        match mutation {
+            TreeMutation::Point => self.point_mutation(tree, rng),
+            TreeMutation::Subtree => self.subtree_mutation(tree, rng),
+            TreeMutation::Hoist => self.hoist_mutation(tree, rng),
+        }
+    }
+
+    fn limited(&self, child: Tree, parent: &Tree) -> Tree {
+        if self.within_limits(&child) {
+            child
+        } else {
+            parent.clone()
+        }
+    }
+
+    fn generate(&self, return_type: Type, depth: usize, full: bool, rng: &mut ThreadRng, nodes: &mut Vec<Node>) {
+        let primitives = &self.primitives;
+        let functions = primitives.functions_of(return_type);
+        let terminals = primitives.terminals_of(return_type);
+        assert!(
+            !functions.is_empty() || !terminals.is_empty(),
+            "no primitive returns {:?}",
+            return_type
+        );
+
+        let use_terminal = if depth == 0 || functions.is_empty() {
+            !terminals.is_empty()
+        } else if full || terminals.is_empty() {
+            false
+        } else {
+            rng.gen_range(0..functions.len() + terminals.len()) < terminals.len()
+        };
+
+        if use_terminal {
+            nodes.push(primitives.terminal_node(*terminals.choose(rng).unwrap(), rng));
+        } else {
+            // Past the depth limit without a terminal, take a function that
+            // closes the tree as soon as possible.
+            let choices = if depth == 0 {
+                primitives.shallowest_functions_of(return_type)
+            } else {
+                functions
+            };
+            let index = *choices.choose(rng).unwrap();
+            nodes.push(Node::Function(index));
+            for &arg_type in &primitives.functions[index].arg_types {
+                self.generate(arg_type, depth.saturating_sub(1), full, rng, nodes);
+            }
+        }
+    }
+}
+
+/// A program evolved by genetic programming.
+#[derive(Clone)]
+pub struct TreeIndividual {
+    pub tree: Tree,
+    /// Raw fitness plus the parsimony penalty.
+    pub fitness: f64,
+    pub raw_fitness: f64,
+    config: Arc<GpConfig>,
+}
+
+impl TreeIndividual {
+    pub fn config(&self) -> &Arc<GpConfig> {
+        &self.config
+    }
+
+    pub fn evaluate(&self, inputs: &[f64]) -> Value {
+        self.tree.evaluate(&self.config.primitives, inputs)
+    }
+
+    pub fn depth(&self) -> usize {
+        self.tree.depth(&self.config.primitives)
+    }
+
+    pub fn size(&self) -> usize {
+        self.tree.len()
+    }
+
+    fn with_tree(&self, tree: Tree) -> Self {
+        self.config.individual(tree)
+    }
+}
+
+impl fmt::Display for TreeIndividual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tree.display(&self.config.primitives))
+    }
+}
+
+impl fmt::Debug for TreeIndividual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeIndividual")
+            .field("tree", &self.to_string())
+            .field("fitness", &self.fitness)
+            .finish()
+    }
+}
+
+impl Individual for TreeIndividual {
+    type Fitness = f64;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.fitness
+    }
+}
+
+impl Crossover for TreeIndividual {
+    fn crossover(&self, other: &Self, rng: &mut ThreadRng) -> Self {
+        self.with_tree(self.config.subtree_crossover(&self.tree, &other.tree, rng))
+    }
+}
+
+impl Mutate for TreeIndividual {
+    /// Applies one of the configured mutations, chosen by weight.
+    fn mutate(&mut self, rng: &mut ThreadRng) {
+        let Ok(&(mutation, _)) = self.config.mutations.choose_weighted(rng, |&(_, weight)| weight) else {
+            return;
+        };
+        *self = self.with_tree(self.config.mutate(&self.tree, mutation, rng));
+    }
+}
+
+/// Subtree crossover as a pluggable operator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SubtreeCrossover;
+
+impl CrossoverOperator<TreeIndividual> for SubtreeCrossover {
+    fn crossover(&self, parents: &[&TreeIndividual], rng: &mut ThreadRng) -> TreeIndividual {
+        parents[0].crossover(parents[1], rng)
+    }
+}
+
+/// Applies a single kind of tree mutation regardless of the configured weights.
+#[derive(Clone, Copy, Debug)]
+pub struct TreeMutationOperator {
+    pub mutation: TreeMutation,
+}
+
+impl TreeMutationOperator {
+    pub fn new(mutation: TreeMutation) -> Self {
+        Self { mutation }
+    }
+}
+
+impl MutationOperator<TreeIndividual> for TreeMutationOperator {
+    fn mutate(&self, individual: &mut TreeIndividual, _progress: f64, rng: &mut ThreadRng) {
+        *individual = individual.with_tree(individual.config.mutate(&individual.tree, self.mutation, rng));
+    }
+}
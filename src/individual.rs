@@ -25,3 +25,57 @@ pub trait Mutate: Individual {
 pub trait Neighbor: Individual {
     fn neighbor(&mut self, rng: &mut rand::rngs::ThreadRng) -> Self;
 }
+
+/// A `Neighbor` whose neighbourhood can be listed move by move, for
+/// optimizers such as `TabuSearch` that compare candidate moves.
+pub trait Neighborhood: Neighbor {
+    type Move: Clone;
+    /// What a tabu list remembers about a move, e.g. the positions it changes.
+    type Attribute: Clone + Eq + std::hash::Hash;
+
+    fn moves(&self) -> Vec<Self::Move>;
+    fn apply(&self, step: &Self::Move) -> Self;
+    /// Attributes of `step` made from `self`; a move is tabu if any of them is.
+    fn attributes(&self, step: &Self::Move) -> Vec<Self::Attribute>;
+}
+
+/// Individuals whose genome is a vector of real numbers.
+pub trait RealVector: Individual {
+    fn genes(&self) -> &[f64];
+    fn genes_mut(&mut self) -> &mut Vec<f64>;
+}
+
+/// A crossover scheme that can be plugged into an optimizer in place of the
+/// individual's own `Crossover` implementation.
+pub trait CrossoverOperator<I>: Send + Sync
+where
+    I: Individual,
+{
+    /// Number of parents combined into one child; at least 2.
+    fn parents(&self) -> usize {
+        2
+    }
+
+    /// Creates a child from `parents`. The child inherits everything except
+    /// the recombined genome from `parents[0]`.
+    fn crossover(&self, parents: &[&I], rng: &mut rand::rngs::ThreadRng) -> I;
+}
+
+/// A mutation scheme that can be plugged into an optimizer in place of the
+/// individual's own `Mutate` implementation.
+pub trait MutationOperator<I>: Send + Sync
+where
+    I: Individual,
+{
+    /// Mutates `individual` in place. `progress` runs from 0.0 at the start of
+    /// the run to 1.0 at its end, for operators whose strength is scheduled.
+    fn mutate(&self, individual: &mut I, progress: f64, rng: &mut rand::rngs::ThreadRng);
+}
+
+/// Individuals whose genome is a permutation of `0..n`.
+pub trait Permutation: Individual {
+    fn permutation(&self) -> &[usize];
+
+    /// Replaces the permutation, updating any state derived from it.
+    fn set_permutation(&mut self, permutation: Vec<usize>);
+}
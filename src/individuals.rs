@@ -1,6 +1,8 @@
 use rand::prelude::*;
-use crate::individual::{Individual, Crossover, Mutate, Neighbor};
+use crate::individual::{Individual, Crossover, Mutate, Neighbor, Neighborhood, RealVector, Permutation};
+use crate::permutation_operators::{order_crossover, inversion};
 use std::f64;
+use std::sync::Arc;
 
 
 #[derive(Clone, Debug)]
@@ -40,6 +42,16 @@ impl Default for NumericIndividual {
     }
 }
 
+impl RealVector for NumericIndividual {
+    fn genes(&self) -> &[f64] {
+        &self.genes
+    }
+
+    fn genes_mut(&mut self) -> &mut Vec<f64> {
+        &mut self.genes
+    }
+}
+
 impl Neighbor for NumericIndividual {
     fn neighbor(&mut self, rng: &mut ThreadRng) -> Self {
         let genes = self.genes.clone().into_iter().map(|v| v + rng.gen_range(-1.0..1.0)).collect();
@@ -76,6 +88,34 @@ impl Mutate for BitStringIndividual {
     }
 }
 
+impl Neighbor for BitStringIndividual {
+    fn neighbor(&mut self, rng: &mut ThreadRng) -> Self {
+        let mut neighbor = self.clone();
+        neighbor.mutate(rng);
+        neighbor
+    }
+}
+
+/// Single bit flips; the attribute of a flip is the bit's position.
+impl Neighborhood for BitStringIndividual {
+    type Move = usize;
+    type Attribute = usize;
+
+    fn moves(&self) -> Vec<usize> {
+        (0..self.bits.len()).collect()
+    }
+
+    fn apply(&self, step: &usize) -> Self {
+        let mut neighbor = self.clone();
+        neighbor.bits[*step] = !neighbor.bits[*step];
+        neighbor
+    }
+
+    fn attributes(&self, step: &usize) -> Vec<usize> {
+        vec![*step]
+    }
+}
+
 impl Default for BitStringIndividual {
     fn default() -> Self {
         let mut rng = thread_rng();
@@ -97,7 +137,13 @@ impl ParticleIndividual {
     pub fn new(dimensions: usize) -> Self {
         let mut rng = thread_rng();
         let position: Vec<f64> = (0..dimensions).map(|_| rng.gen_range(-10.0..10.0)).collect();
-        let velocity: Vec<f64> = (0..dimensions).map(|_| rng.gen_range(-1.0..1.0)).collect();
+        Self::from_position(position)
+    }
+
+    /// Creates a particle at `position` with a random velocity in `[-1, 1)`.
+    pub fn from_position(position: Vec<f64>) -> Self {
+        let mut rng = thread_rng();
+        let velocity: Vec<f64> = (0..position.len()).map(|_| rng.gen_range(-1.0..1.0)).collect();
         let personal_best_position = position.clone();
         let personal_best_score = f64::INFINITY;
         Self {
@@ -136,15 +182,34 @@ impl Default for ParticleIndividual {
     }
 }
 
+/// A closed tour through the cities of a distance matrix.
+///
+/// Every tour keeps a handle on its distance matrix, so the permutation
+/// operators can recompute `length` after changing it. The tour lists every
+/// city once; the return edge to the first city is implied.
 #[derive(Clone, Debug)]
 pub struct TourIndividual {
     pub tour: Vec<usize>,
     pub length: f64,
+    distances: Arc<Vec<Vec<f64>>>,
 }
 
 impl TourIndividual {
-    pub fn new(tour: Vec<usize>, length: f64) -> Self {
-        Self { tour, length }
+    pub fn from_permutation(tour: Vec<usize>, distances: Arc<Vec<Vec<f64>>>) -> Self {
+        let length = tour_length(&tour, &distances);
+        Self { tour, length, distances }
+    }
+
+    /// A uniformly random tour over all cities of `distances`.
+    pub fn random(distances: Arc<Vec<Vec<f64>>>, rng: &mut ThreadRng) -> Self {
+        let mut tour: Vec<usize> = (0..distances.len()).collect();
+        tour.shuffle(rng);
+        Self::from_permutation(tour, distances)
+    }
+
+    /// The distance matrix the tour length is computed from.
+    pub fn distances(&self) -> &Arc<Vec<Vec<f64>>> {
+        &self.distances
     }
 }
 
@@ -156,8 +221,72 @@ impl Individual for TourIndividual {
     }
 }
 
-impl Default for TourIndividual {
-    fn default() -> Self {
-        Self::new([1, 1, 1, 1].to_vec(), 10.5)
+impl Permutation for TourIndividual {
+    fn permutation(&self) -> &[usize] {
+        &self.tour
     }
+
+    fn set_permutation(&mut self, permutation: Vec<usize>) {
+        self.length = tour_length(&permutation, &self.distances);
+        self.tour = permutation;
+    }
+}
+
+impl Crossover for TourIndividual {
+    fn crossover(&self, other: &Self, rng: &mut ThreadRng) -> Self {
+        let mut child = self.clone();
+        child.set_permutation(order_crossover(self.permutation(), other.permutation(), rng));
+        child
+    }
+}
+
+impl Mutate for TourIndividual {
+    fn mutate(&mut self, rng: &mut ThreadRng) {
+        let mut tour = self.permutation().to_vec();
+        inversion(&mut tour, rng);
+        self.set_permutation(tour);
+    }
+}
+
+impl Neighbor for TourIndividual {
+    fn neighbor(&mut self, rng: &mut ThreadRng) -> Self {
+        let mut neighbor = self.clone();
+        neighbor.mutate(rng);
+        neighbor
+    }
+}
+
+/// 2-opt moves: `(i, j)` reverses the tour between positions `i` and `j`.
+/// The attributes of a move are the two cities at its ends.
+impl Neighborhood for TourIndividual {
+    type Move = (usize, usize);
+    type Attribute = usize;
+
+    fn moves(&self) -> Vec<(usize, usize)> {
+        let n = self.permutation().len();
+        (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .filter(|&(i, j)| j - i < n - 1)
+            .collect()
+    }
+
+    fn apply(&self, &(i, j): &(usize, usize)) -> Self {
+        let mut tour = self.permutation().to_vec();
+        tour[i..=j].reverse();
+        let mut neighbor = self.clone();
+        neighbor.set_permutation(tour);
+        neighbor
+    }
+
+    fn attributes(&self, &(i, j): &(usize, usize)) -> Vec<usize> {
+        let tour = self.permutation();
+        vec![tour[i], tour[j]]
+    }
+}
+
+fn tour_length(tour: &[usize], distances: &[Vec<f64>]) -> f64 {
+    tour.iter()
+        .zip(tour.iter().cycle().skip(1))
+        .map(|(&from, &to)| distances[from][to])
+        .sum()
 }
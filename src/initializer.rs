@@ -0,0 +1,164 @@
+use rand::prelude::*;
+use crate::individual::{Individual, RealVector, FitnessValue};
+use crate::sampling::{Sampler, opposition_based};
+
+
+pub type Generator<I> = Box<dyn Fn(&mut ThreadRng) -> I + Send + Sync>;
+pub type BatchGenerator<I> = Box<dyn Fn(usize, &mut ThreadRng) -> Vec<I> + Send + Sync>;
+
+enum Source<I> {
+    Each(Generator<I>),
+    Batch(BatchGenerator<I>),
+}
+
+/// Builds starting solutions for an optimizer from a generator closure, a
+/// user-supplied seed population, or both.
+///
+/// Seeds are used first; any remaining slots are filled by the generator. An
+/// initializer without a generator fills them with seeds drawn at random. Generators either build
+/// one individual at a time or, for space-filling designs, a whole batch.
+pub struct Initializer<I>
+where
+    I: Individual,
+{
+    seeds: Vec<I>,
+    generator: Option<Source<I>>,
+}
+
+impl<I> Initializer<I>
+where
+    I: Individual,
+{
+    pub fn from_fn<F>(generator: F) -> Self
+    where
+        F: Fn(&mut ThreadRng) -> I + Send + Sync + 'static,
+    {
+        Self {
+            seeds: Vec::new(),
+            generator: Some(Source::Each(Box::new(generator))),
+        }
+    }
+
+    /// Uses `generator` to create all missing individuals in one call.
+    pub fn from_batch<F>(generator: F) -> Self
+    where
+        F: Fn(usize, &mut ThreadRng) -> Vec<I> + Send + Sync + 'static,
+    {
+        Self {
+            seeds: Vec::new(),
+            generator: Some(Source::Batch(Box::new(generator))),
+        }
+    }
+
+    pub fn from_population(seeds: Vec<I>) -> Self {
+        assert!(!seeds.is_empty(), "a seed population needs at least one individual");
+        Self {
+            seeds,
+            generator: None,
+        }
+    }
+
+    /// Warm-starts from `seeds` and generates the rest of the population.
+    pub fn mixed<F>(seeds: Vec<I>, generator: F) -> Self
+    where
+        F: Fn(&mut ThreadRng) -> I + Send + Sync + 'static,
+    {
+        Self {
+            seeds,
+            generator: Some(Source::Each(Box::new(generator))),
+        }
+    }
+
+    /// Adds `seeds` in front of the existing seeds and the generated individuals.
+    pub fn with_seeds(mut self, mut seeds: Vec<I>) -> Self {
+        seeds.append(&mut self.seeds);
+        self.seeds = seeds;
+        self
+    }
+
+    pub fn seeds(&self) -> &[I] {
+        &self.seeds
+    }
+
+    /// Returns a newly generated individual, or a random seed if there is no generator.
+    pub fn generate(&self, rng: &mut ThreadRng) -> I {
+        match &self.generator {
+            Some(Source::Each(generator)) => generator(rng),
+            Some(Source::Batch(generator)) => generator(1, rng).pop().unwrap(),
+            None => self.seeds.choose(rng).unwrap().clone(),
+        }
+    }
+
+    /// Returns the first seed, or a generated individual if there are no seeds.
+    pub fn individual(&self, rng: &mut ThreadRng) -> I {
+        match self.seeds.first() {
+            Some(seed) => seed.clone(),
+            None => self.generate(rng),
+        }
+    }
+
+    pub fn population(&self, size: usize, rng: &mut ThreadRng) -> Vec<I> {
+        let mut population: Vec<I> = self.seeds.iter().take(size).cloned().collect();
+        let missing = size - population.len();
+        match &self.generator {
+            Some(Source::Each(generator)) => population.extend((0..missing).map(|_| generator(rng))),
+            Some(Source::Batch(generator)) => population.extend(generator(missing, rng)),
+            None => population.extend((0..missing).map(|_| self.generate(rng))),
+        }
+        population
+    }
+}
+
+impl<I> Default for Initializer<I>
+where
+    I: Individual + Default,
+{
+    fn default() -> Self {
+        Self::from_fn(|_| I::default())
+    }
+}
+
+impl<I> Initializer<I>
+where
+    I: RealVector + Default + 'static,
+{
+    /// Places individuals inside `bounds` with `sampler`, starting from
+    /// `I::default()` and replacing its genes.
+    pub fn sampled<S>(sampler: S, bounds: Vec<(f64, f64)>) -> Self
+    where
+        S: Sampler + 'static,
+    {
+        Self::from_batch(move |count, rng| {
+            sampler
+                .sample(count, &bounds, rng)
+                .into_iter()
+                .map(with_genes)
+                .collect()
+        })
+    }
+
+    /// Like `sampled`, but applies opposition-based learning: each sampled
+    /// point competes with its opposite and the fitter half is kept.
+    pub fn opposition_based<S>(sampler: S, bounds: Vec<(f64, f64)>) -> Self
+    where
+        S: Sampler + 'static,
+        I::Fitness: FitnessValue,
+    {
+        Self::from_batch(move |count, rng| {
+            let fitness = |genes: &[f64]| with_genes::<I>(genes.to_vec()).fitness().to_f64();
+            opposition_based(&sampler, count, &bounds, fitness, rng)
+                .into_iter()
+                .map(with_genes)
+                .collect()
+        })
+    }
+}
+
+fn with_genes<I>(genes: Vec<f64>) -> I
+where
+    I: RealVector + Default,
+{
+    let mut individual = I::default();
+    *individual.genes_mut() = genes;
+    individual
+}
@@ -0,0 +1,253 @@
+use rand::prelude::*;
+use std::thread;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{Individual, Crossover, Mutate, FitnessValue};
+use crate::observer::Observer;
+use crate::genetic_algorithm::{GeneticAlgorithm, Breeding};
+use crate::rate_control::Rates;
+
+
+/// Which islands receive the migrants of an island.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Topology {
+    /// Island `i` sends to island `i + 1`, the last one to the first.
+    Ring,
+    /// Every island sends to every other island.
+    FullyConnected,
+    /// Every island sends to one other island chosen at random each migration.
+    Random,
+}
+
+impl Topology {
+    pub fn destinations(&self, island: usize, islands: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        if islands < 2 {
+            return Vec::new();
+        }
+        match self {
+            Topology::Ring => vec![(island + 1) % islands],
+            Topology::FullyConnected => (0..islands).filter(|&other| other != island).collect(),
+            Topology::Random => {
+                let other = rng.gen_range(0..islands - 1);
+                vec![if other >= island { other + 1 } else { other }]
+            }
+        }
+    }
+}
+
+/// Island-model GA: `islands` sub-populations evolve independently with the
+/// settings of `ga` (each of `ga.population_size` individuals, with its
+/// generation model, niching, rate control and local search) and every
+/// `migration_interval` generations send copies of their `migration_size`
+/// best individuals along `topology`, where they replace the worst ones.
+///
+/// Observers see the union of all islands at the start of each generation,
+/// the metrics `island_<k>_best` and `island_<k>_mean` for every island `k`,
+/// and `mutation_rate` and `crossover_rate` averaged over the islands.
+pub struct IslandModel<I>
+where
+    I: Individual,
+{
+    pub ga: GeneticAlgorithm<I>,
+    pub islands: usize,
+    pub topology: Topology,
+    /// Generations between migrations; 0 disables migration.
+    pub migration_interval: usize,
+    pub migration_size: usize,
+    // Set by `parallel()`, where the individuals are known to be `Send + Sync`.
+    evolve_parallel: Option<Evolve<I>>,
+}
+
+type Evolve<I> = fn(&IslandModel<I>, &mut [Breeding<I>], usize) -> Vec<Generation<I>>;
+
+/// One generation of one island: the population it started from, the scores
+/// of its members, the steady-state children born during it and the rates
+/// in effect.
+struct Generation<I> {
+    population: Vec<I>,
+    scores: Vec<f64>,
+    born: Vec<I>,
+    rates: Rates,
+}
+
+impl<I> IslandModel<I>
+where
+    I: Individual,
+{
+    pub fn new(ga: GeneticAlgorithm<I>, islands: usize) -> Self {
+        Self {
+            ga,
+            islands,
+            topology: Topology::Ring,
+            migration_interval: 10,
+            migration_size: 2,
+            evolve_parallel: None,
+        }
+    }
+
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    pub fn with_migration(mut self, interval: usize, size: usize) -> Self {
+        self.migration_interval = interval;
+        self.migration_size = size;
+        self
+    }
+
+    pub fn is_parallel(&self) -> bool {
+        self.evolve_parallel.is_some()
+    }
+}
+
+impl<I> IslandModel<I>
+where
+    I: Individual + Crossover + Mutate + Send + Sync,
+{
+    /// Evolves the islands on separate threads, one generation at a time.
+    pub fn parallel(mut self) -> Self {
+        self.evolve_parallel = Some(Self::evolve_threads);
+        self
+    }
+
+    fn evolve_threads(&self, islands: &mut [Breeding<I>], generation: usize) -> Vec<Generation<I>> {
+        thread::scope(|scope| {
+            let handles: Vec<_> = islands
+                .iter_mut()
+                .map(|island| scope.spawn(move || self.evolve(island, generation)))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().expect("island thread panicked"))
+                .collect()
+        })
+    }
+}
+
+impl<I> IslandModel<I>
+where
+    I: Individual + Crossover + Mutate,
+{
+    /// Runs one generation on one island.
+    fn evolve(&self, island: &mut Breeding<I>, generation: usize) -> Generation<I> {
+        let population = island.population.clone();
+        let scores = island.scores.clone();
+        let mut born = Vec::new();
+        let rates = self.ga.breed_generation(island, generation, &mut thread_rng(), |child| born.push(child.clone()));
+        Generation { population, scores, born, rates }
+    }
+
+    fn evolve_all(&self, islands: &mut [Breeding<I>], generation: usize) -> Vec<Generation<I>> {
+        match self.evolve_parallel {
+            Some(evolve) => evolve(self, islands, generation),
+            None => islands.iter_mut().map(|island| self.evolve(island, generation)).collect(),
+        }
+    }
+
+    fn migrate(&self, islands: &mut [Breeding<I>], rng: &mut ThreadRng) {
+        let emigrants: Vec<Vec<(I, f64)>> = islands
+            .iter()
+            .map(|island| {
+                ranked(&island.scores)
+                    .iter()
+                    .take(self.migration_size)
+                    .map(|&index| (island.population[index].clone(), island.scores[index]))
+                    .collect()
+            })
+            .collect();
+
+        let mut arrivals: Vec<Vec<(I, f64)>> = vec![Vec::new(); islands.len()];
+        for (source, migrants) in emigrants.into_iter().enumerate() {
+            for destination in self.topology.destinations(source, islands.len(), rng) {
+                arrivals[destination].extend(migrants.iter().cloned());
+            }
+        }
+
+        for (island, immigrants) in islands.iter_mut().zip(arrivals) {
+            let order = ranked(&island.scores);
+            for (&index, (immigrant, score)) in order.iter().rev().zip(immigrants) {
+                island.replace(index, immigrant, score);
+            }
+        }
+    }
+}
+
+impl<I> Optimizer<I> for IslandModel<I>
+where
+    I: Individual + Crossover + Mutate,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut rng = thread_rng();
+        let mut islands: Vec<Breeding<I>> = (0..self.islands)
+            .map(|_| self.ga.start_breeding(self.ga.initialize_population(&mut rng)))
+            .collect();
+
+        let combined: Vec<I> = islands.iter().flat_map(|island| island.population.iter().cloned()).collect();
+        for observer in observers.iter_mut() {
+            observer.on_start();
+            observer.on_iteration(0, &combined);
+        }
+
+        // One generation at a time, so that stop requests are seen as soon
+        // as they are made.
+        for generation in 1..=self.ga.generations {
+            let islands_generation = self.evolve_all(&mut islands, generation);
+
+            let mut combined = Vec::with_capacity(self.islands * self.ga.population_size);
+            let mut statistics = Vec::with_capacity(self.islands);
+            let mut rates = Rates::new(0.0, 0.0);
+            for island in &islands_generation {
+                statistics.push(statistics_of(&island.scores));
+                rates.mutation += island.rates.mutation / islands_generation.len() as f64;
+                rates.crossover += island.rates.crossover / islands_generation.len() as f64;
+                for individual in island.population.iter().chain(&island.born) {
+                    archive.add(individual.clone());
+                }
+                combined.extend(island.population.iter().cloned());
+            }
+
+            for observer in observers.iter_mut() {
+                observer.on_iteration(generation, &combined);
+                for (index, &(best, mean)) in statistics.iter().enumerate() {
+                    observer.on_metric(generation, &format!("island_{}_best", index), best);
+                    observer.on_metric(generation, &format!("island_{}_mean", index), mean);
+                }
+            }
+            self.ga.report_rates(generation, rates, observers);
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+
+            if self.migration_interval > 0
+                && generation.is_multiple_of(self.migration_interval)
+                && generation < self.ga.generations
+            {
+                self.migrate(&mut islands, &mut rng);
+            }
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
+
+/// Indices of `scores` from best to worst.
+fn ranked(scores: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap());
+    order
+}
+
+/// Best and mean of `scores`.
+fn statistics_of(scores: &[f64]) -> (f64, f64) {
+    let best = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+    let mean = scores.iter().sum::<f64>() / scores.len().max(1) as f64;
+    (best, mean)
+}
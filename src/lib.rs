@@ -3,17 +3,44 @@ pub use optimizer::Optimizer;
 
 pub mod archive;
 pub use archive::{Archive, BasicArchive};
+pub mod shared_archive;
+pub use shared_archive::SharedArchive;
 
 pub mod individual;
-pub use individual::{Individual, FitnessValue};
+pub use individual::{Individual, FitnessValue, RealVector, Permutation, Neighborhood};
 
 pub mod individuals;
+pub mod random;
+pub mod bounds;
+pub mod sampling;
+pub mod initializer;
+pub use initializer::Initializer;
 
 pub mod observer;
 pub mod distribution_observers;
 pub mod logging_observers;
+pub mod metric_observers;
 
+pub mod selection;
+pub mod rate_control;
+pub mod niching;
+pub mod local_search;
+pub mod real_crossover;
+pub mod real_mutation;
+pub mod permutation_operators;
 pub mod genetic_algorithm;
+pub mod cooling;
+pub mod acceptance;
 pub mod simulated_annealing;
+pub mod tabu_search;
 pub mod particle_swarm;
 pub mod ant_colony;
+pub mod differential_evolution;
+pub mod cma_es;
+pub mod evolution_strategy;
+pub mod map_elites;
+pub mod novelty_search;
+pub mod island_model;
+pub mod restarts;
+pub mod gp;
+pub mod symbolic_regression;
@@ -0,0 +1,139 @@
+use rand::prelude::*;
+use std::sync::Arc;
+use crate::individual::{Individual, Neighbor, FitnessValue};
+
+
+pub type Search<I> = Arc<dyn Fn(&I, usize, &mut ThreadRng) -> I + Send + Sync>;
+
+/// What a genetic algorithm does with the result of local search.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Learning {
+    /// The improved genome replaces the offspring.
+    Lamarckian,
+    /// The offspring keeps its genome but competes with the improved fitness.
+    Baldwinian,
+}
+
+/// Local search applied to a genetic algorithm's offspring, turning it into
+/// a memetic algorithm. The search receives the offspring and an evaluation
+/// `budget` and returns the solution it ends on.
+pub struct LocalSearch<I>
+where
+    I: Individual,
+{
+    /// Chance that a given offspring is improved.
+    pub probability: f64,
+    pub budget: usize,
+    pub learning: Learning,
+    search: Search<I>,
+}
+
+impl<I> LocalSearch<I>
+where
+    I: Individual,
+{
+    /// Lamarckian local search with the given closure.
+    pub fn new<S>(search: S, probability: f64, budget: usize) -> Self
+    where
+        S: Fn(&I, usize, &mut ThreadRng) -> I + Send + Sync + 'static,
+    {
+        Self {
+            probability,
+            budget,
+            learning: Learning::Lamarckian,
+            search: Arc::new(search),
+        }
+    }
+
+    pub fn with_learning(mut self, learning: Learning) -> Self {
+        self.learning = learning;
+        self
+    }
+
+    pub fn search(&self, individual: &I, rng: &mut ThreadRng) -> I {
+        (self.search)(individual, self.budget, rng)
+    }
+
+    /// With probability `probability`, improves `individual` and returns the
+    /// genome to keep together with the score it competes with; otherwise
+    /// returns it unchanged with its own fitness. A search result worse than
+    /// the offspring is discarded.
+    pub fn learn(&self, individual: I, rng: &mut ThreadRng) -> (I, f64) {
+        let score = individual.fitness().to_f64();
+        if rng.gen::<f64>() >= self.probability {
+            return (individual, score);
+        }
+
+        let improved = self.search(&individual, rng);
+        let improved_score = improved.fitness().to_f64();
+        if improved_score >= score {
+            return (individual, score);
+        }
+
+        match self.learning {
+            Learning::Lamarckian => (improved, improved_score),
+            Learning::Baldwinian => (individual, improved_score),
+        }
+    }
+}
+
+impl<I> LocalSearch<I>
+where
+    I: Individual + Neighbor + 'static,
+{
+    /// Lamarckian first-improvement hill climbing; see `hill_climb`.
+    pub fn hill_climber(probability: f64, budget: usize) -> Self {
+        Self::new(hill_climb, probability, budget)
+    }
+}
+
+/// First-improvement hill climbing: draws up to `budget` neighbours and moves
+/// to each one that is strictly better than the current solution.
+pub fn hill_climb<I>(start: &I, budget: usize, rng: &mut ThreadRng) -> I
+where
+    I: Individual + Neighbor,
+{
+    let mut current = start.clone();
+    let mut score = current.fitness().to_f64();
+    for _ in 0..budget {
+        let candidate = current.neighbor(rng);
+        let candidate_score = candidate.fitness().to_f64();
+        if candidate_score < score {
+            current = candidate;
+            score = candidate_score;
+        }
+    }
+    current
+}
+
+/// Steepest-descent hill climbing: each step evaluates `sample` neighbours
+/// and moves to the best of them if it improves on the current solution,
+/// stopping when none does or after `budget` evaluations.
+pub fn steepest_descent<I>(start: &I, budget: usize, sample: usize, rng: &mut ThreadRng) -> I
+where
+    I: Individual + Neighbor,
+{
+    let mut current = start.clone();
+    let mut score = current.fitness().to_f64();
+    let sample = sample.max(1);
+    let mut evaluations = 0;
+
+    while evaluations + sample <= budget {
+        let best = (0..sample)
+            .map(|_| {
+                let candidate = current.neighbor(rng);
+                let candidate_score = candidate.fitness().to_f64();
+                (candidate, candidate_score)
+            })
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .unwrap();
+        evaluations += sample;
+
+        if best.1 >= score {
+            break;
+        }
+        current = best.0;
+        score = best.1;
+    }
+    current
+}
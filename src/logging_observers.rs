@@ -54,6 +54,12 @@ where
         }
     }
 
+    fn on_metric(&mut self, iteration: usize, name: &str, value: f64) {
+        if self.log_fitness {
+            println!("Iteration {}: {} = {}", iteration, name, value);
+        }
+    }
+
     fn on_finish(&mut self) {
         println!("Optimization finished.");
     }
@@ -0,0 +1,364 @@
+use rand::prelude::*;
+use std::sync::Arc;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{Individual, Crossover, Mutate, FitnessValue};
+use crate::observer::Observer;
+use crate::initializer::Initializer;
+
+
+/// Maps a solution to its behaviour descriptor.
+pub type BehaviourDescriptor<I> = Arc<dyn Fn(&I) -> Vec<f64> + Send + Sync>;
+
+/// Partition of the behaviour space into the cells of a MAP-Elites archive.
+#[derive(Clone, Debug)]
+pub enum Tessellation {
+    /// A regular grid with `bins[d]` equally sized bins along dimension `d`.
+    Grid {
+        bounds: Vec<(f64, f64)>,
+        bins: Vec<usize>,
+    },
+    /// A centroidal Voronoi tessellation; each descriptor belongs to its nearest centroid.
+    Cvt {
+        centroids: Vec<Vec<f64>>,
+    },
+}
+
+impl Tessellation {
+    pub fn grid(bounds: Vec<(f64, f64)>, bins: Vec<usize>) -> Self {
+        assert_eq!(bounds.len(), bins.len(), "one bin count is required per descriptor dimension");
+        assert!(bins.iter().all(|&count| count > 0), "every grid dimension needs at least one bin");
+        Tessellation::Grid { bounds, bins }
+    }
+
+    /// Builds a CVT with `cells` centroids by running Lloyd's algorithm on
+    /// `samples` points drawn uniformly from `bounds`.
+    pub fn cvt(bounds: &[(f64, f64)], cells: usize, samples: usize, iterations: usize) -> Self {
+        assert!(cells > 0, "a CVT needs at least one cell");
+        let mut rng = thread_rng();
+        let points: Vec<Vec<f64>> = (0..samples.max(cells))
+            .map(|_| bounds.iter().map(|&(low, high)| rng.gen_range(low..=high)).collect())
+            .collect();
+        let mut centroids: Vec<Vec<f64>> = points.choose_multiple(&mut rng, cells).cloned().collect();
+
+        for _ in 0..iterations {
+            let mut sums = vec![vec![0.0; bounds.len()]; cells];
+            let mut counts = vec![0usize; cells];
+
+            for point in &points {
+                let cell = nearest_centroid(&centroids, point);
+                counts[cell] += 1;
+                for (sum, x) in sums[cell].iter_mut().zip(point) {
+                    *sum += x;
+                }
+            }
+
+            for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+                if count > 0 {
+                    *centroid = sum.into_iter().map(|s| s / count as f64).collect();
+                }
+            }
+        }
+
+        Tessellation::Cvt { centroids }
+    }
+
+    pub fn cell_count(&self) -> usize {
+        match self {
+            Tessellation::Grid { bins, .. } => bins.iter().product(),
+            Tessellation::Cvt { centroids } => centroids.len(),
+        }
+    }
+
+    /// Number of behaviour descriptor dimensions.
+    pub fn dimensions(&self) -> usize {
+        match self {
+            Tessellation::Grid { bounds, .. } => bounds.len(),
+            Tessellation::Cvt { centroids } => centroids.first().map_or(0, |centroid| centroid.len()),
+        }
+    }
+
+    /// Returns the index of the cell containing `descriptor`. Descriptors outside
+    /// the grid bounds are clamped into the border cells.
+    pub fn cell_index(&self, descriptor: &[f64]) -> usize {
+        assert_eq!(descriptor.len(), self.dimensions(), "descriptor length does not match the tessellation");
+        match self {
+            Tessellation::Grid { bounds, bins } => {
+                let mut index = 0;
+                for ((&x, &(low, high)), &count) in descriptor.iter().zip(bounds).zip(bins) {
+                    let scaled = ((x - low) / (high - low) * count as f64).floor();
+                    let bin = (scaled.max(0.0) as usize).min(count - 1);
+                    index = index * count + bin;
+                }
+                index
+            }
+            Tessellation::Cvt { centroids } => nearest_centroid(centroids, descriptor),
+        }
+    }
+}
+
+fn nearest_centroid(centroids: &[Vec<f64>], point: &[f64]) -> usize {
+    centroids
+        .iter()
+        .map(|centroid| centroid.iter().zip(point).map(|(c, x)| (c - x) * (c - x)).sum::<f64>())
+        .enumerate()
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
+
+/// Archive that keeps the best solution found in each cell of a behaviour space.
+#[derive(Clone)]
+pub struct MapElitesArchive<I>
+where
+    I: Individual,
+{
+    pub tessellation: Tessellation,
+    pub qd_offset: f64,
+    descriptor: BehaviourDescriptor<I>,
+    cells: Vec<Option<I>>,
+}
+
+impl<I> MapElitesArchive<I>
+where
+    I: Individual,
+{
+    pub fn new<D>(tessellation: Tessellation, descriptor: D) -> Self
+    where
+        D: Fn(&I) -> Vec<f64> + Send + Sync + 'static,
+    {
+        assert!(tessellation.cell_count() > 0, "a MAP-Elites archive needs at least one cell");
+        let cells = vec![None; tessellation.cell_count()];
+        Self {
+            tessellation,
+            qd_offset: 0.0,
+            descriptor: Arc::new(descriptor),
+            cells,
+        }
+    }
+
+    /// Sets the fitness value treated as zero quality when computing the QD-score.
+    /// It should be no better than the worst fitness expected in the archive.
+    pub fn with_qd_offset(mut self, qd_offset: f64) -> Self {
+        self.qd_offset = qd_offset;
+        self
+    }
+
+    /// Returns an archive with the same tessellation and descriptor but no elites.
+    pub fn empty_clone(&self) -> Self {
+        Self {
+            tessellation: self.tessellation.clone(),
+            qd_offset: self.qd_offset,
+            descriptor: Arc::clone(&self.descriptor),
+            cells: vec![None; self.cells.len()],
+        }
+    }
+
+    pub fn descriptor(&self, solution: &I) -> Vec<f64> {
+        (self.descriptor)(solution)
+    }
+
+    pub fn elites(&self) -> impl Iterator<Item = &I> {
+        self.cells.iter().flatten()
+    }
+
+    pub fn get_cell(&self, index: usize) -> Option<&I> {
+        self.cells.get(index).and_then(|cell| cell.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.elites().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().all(|cell| cell.is_none())
+    }
+
+    /// Fraction of cells that hold an elite.
+    pub fn coverage(&self) -> f64 {
+        self.len() as f64 / self.cells.len() as f64
+    }
+
+    /// Sum over all elites of `qd_offset - fitness` (lower fitness is better).
+    pub fn qd_score(&self) -> f64
+    where
+        I::Fitness: FitnessValue,
+    {
+        self.elites()
+            .map(|elite| self.qd_offset - elite.fitness().to_f64())
+            .sum()
+    }
+}
+
+impl<I> Archive for MapElitesArchive<I>
+where
+    I: Individual,
+{
+    type Solution = I;
+    type Fitness = I::Fitness;
+
+    fn add(&mut self, solution: I) {
+        let index = self.tessellation.cell_index(&self.descriptor(&solution));
+        let cell = &mut self.cells[index];
+        let replace = match cell {
+            Some(elite) => solution.fitness() < elite.fitness(),
+            None => true,
+        };
+        if replace {
+            *cell = Some(solution);
+        }
+    }
+
+    fn get_best(&self) -> Option<&Self::Solution> {
+        self.elites()
+            .min_by(|a, b| a.fitness().partial_cmp(&b.fitness()).unwrap())
+    }
+
+    fn solutions(&self) -> Vec<&Self::Solution> {
+        self.elites().collect()
+    }
+}
+
+pub struct MapElites<I>
+where
+    I: Individual,
+{
+    pub grid: MapElitesArchive<I>,
+    pub initial_samples: usize,
+    pub batch_size: usize,
+    pub iterations: usize,
+    pub crossover_rate: f64,
+    pub mutation_rate: f64,
+    pub initializer: Initializer<I>,
+}
+
+impl<I> MapElites<I>
+where
+    I: Individual + Default,
+{
+    pub fn new(
+        grid: MapElitesArchive<I>,
+        initial_samples: usize,
+        batch_size: usize,
+        iterations: usize,
+        crossover_rate: f64,
+        mutation_rate: f64,
+    ) -> Self {
+        Self::from_initializer(
+            Initializer::default(),
+            grid,
+            initial_samples,
+            batch_size,
+            iterations,
+            crossover_rate,
+            mutation_rate,
+        )
+    }
+}
+
+impl<I> MapElites<I>
+where
+    I: Individual,
+{
+    /// Fills the grid with `initial_samples` individuals from `initializer`
+    /// before evolving the elites.
+    pub fn from_initializer(
+        initializer: Initializer<I>,
+        grid: MapElitesArchive<I>,
+        initial_samples: usize,
+        batch_size: usize,
+        iterations: usize,
+        crossover_rate: f64,
+        mutation_rate: f64,
+    ) -> Self {
+        Self {
+            grid,
+            initial_samples,
+            batch_size,
+            iterations,
+            crossover_rate,
+            mutation_rate,
+            initializer,
+        }
+    }
+
+    fn report<O>(&self, grid: &MapElitesArchive<I>, iteration: usize, observers: &mut [O])
+    where
+        I::Fitness: FitnessValue,
+        O: Observer<I>,
+    {
+        let elites: Vec<I> = grid.elites().cloned().collect();
+        let coverage = grid.coverage();
+        let qd_score = grid.qd_score();
+
+        for observer in observers.iter_mut() {
+            observer.on_iteration(iteration, &elites);
+            observer.on_metric(iteration, "coverage", coverage);
+            observer.on_metric(iteration, "qd_score", qd_score);
+        }
+    }
+}
+
+impl<I> Optimizer<I> for MapElites<I>
+where
+    I: Individual + Crossover + Mutate,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut rng = thread_rng();
+        let mut grid = self.grid.empty_clone();
+
+        for individual in self.initializer.population(self.initial_samples, &mut rng) {
+            archive.add(individual.clone());
+            grid.add(individual);
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_start();
+        }
+        self.report(&grid, 0, observers);
+
+        for iteration in 1..=self.iterations {
+            let elites: Vec<&I> = grid.elites().collect();
+            if elites.is_empty() {
+                break;
+            }
+
+            let mut offspring = Vec::with_capacity(self.batch_size);
+            for _ in 0..self.batch_size {
+                let parent1 = elites[rng.gen_range(0..elites.len())];
+
+                let mut child = if rng.gen::<f64>() < self.crossover_rate {
+                    let parent2 = elites[rng.gen_range(0..elites.len())];
+                    parent1.crossover(parent2, &mut rng)
+                } else {
+                    parent1.clone()
+                };
+
+                if rng.gen::<f64>() < self.mutation_rate {
+                    child.mutate(&mut rng);
+                }
+
+                offspring.push(child);
+            }
+
+            for child in offspring {
+                archive.add(child.clone());
+                grid.add(child);
+            }
+
+            self.report(&grid, iteration, observers);
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::observer::Observer;
+use crate::individual::Individual;
+use std::marker::PhantomData;
+
+
+pub struct MetricObserver<I>
+where
+    I: Individual,
+{
+    pub metrics: Vec<(usize, String, f64)>,
+    p: PhantomData<I>,
+}
+
+impl<I> MetricObserver<I>
+where
+    I: Individual,
+{
+    pub fn new() -> Self {
+        Self {
+            metrics: Vec::new(),
+            p: PhantomData,
+        }
+    }
+
+    /// Returns the recorded values of the metric called `name`, in reporting order.
+    pub fn values(&self, name: &str) -> Vec<f64> {
+        self.metrics
+            .iter()
+            .filter(|(_, metric, _)| metric == name)
+            .map(|&(_, _, value)| value)
+            .collect()
+    }
+}
+
+impl<I> Default for MetricObserver<I>
+where
+    I: Individual,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> Observer<I> for MetricObserver<I>
+where
+    I: Individual,
+{
+    fn on_start(&mut self) {}
+
+    fn on_iteration(&mut self, _iteration: usize, _population: &[I]) {}
+
+    fn on_metric(&mut self, iteration: usize, name: &str, value: f64) {
+        self.metrics.push((iteration, name.to_string(), value));
+    }
+
+    fn on_finish(&mut self) {}
+}
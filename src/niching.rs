@@ -0,0 +1,138 @@
+use rand::prelude::*;
+use std::sync::Arc;
+use crate::individual::{Individual, RealVector};
+use crate::novelty_search::euclidean_distance;
+
+
+pub type Distance<I> = Arc<dyn Fn(&I, &I) -> f64 + Send + Sync>;
+
+/// Strategy for keeping a genetic algorithm's population spread over several
+/// optima.
+#[derive(Clone, Copy, Debug)]
+pub enum NichingMethod {
+    /// Goldberg and Richardson's fitness sharing: each score is degraded by
+    /// the niche count `sum(max(0, 1 - (d / radius)^alpha))` over the population.
+    FitnessSharing { radius: f64, alpha: f64 },
+    /// Pétrowski's clearing: within each niche of the given `radius` only the
+    /// best `capacity` individuals keep their score; the rest get the worst
+    /// score in the population.
+    Clearing { radius: f64, capacity: usize },
+    /// Mahfoud's deterministic crowding: parents are paired at random and each
+    /// child replaces the closer of its two parents if it is at least as good.
+    DeterministicCrowding,
+    /// Harik's restricted tournament selection: each child competes with the
+    /// closest of `window` random members of the population and replaces it
+    /// if it is better.
+    RestrictedTournament { window: usize },
+}
+
+/// A niching method together with the distance between individuals it uses.
+pub struct Niching<I>
+where
+    I: Individual,
+{
+    pub method: NichingMethod,
+    distance: Distance<I>,
+}
+
+impl<I> Niching<I>
+where
+    I: Individual,
+{
+    pub fn new<D>(method: NichingMethod, distance: D) -> Self
+    where
+        D: Fn(&I, &I) -> f64 + Send + Sync + 'static,
+    {
+        Self {
+            method,
+            distance: Arc::new(distance),
+        }
+    }
+
+    pub fn distance(&self, a: &I, b: &I) -> f64 {
+        (self.distance)(a, b)
+    }
+
+    /// Applies fitness sharing or clearing to `scores` (lower is better); the
+    /// replacement-based methods leave them unchanged.
+    pub fn adjust_scores(&self, population: &[I], scores: &[f64]) -> Vec<f64> {
+        match self.method {
+            NichingMethod::FitnessSharing { radius, alpha } => self.shared(population, scores, radius, alpha),
+            NichingMethod::Clearing { radius, capacity } => self.cleared(population, scores, radius, capacity),
+            _ => scores.to_vec(),
+        }
+    }
+
+    /// Index of the member of `population` closest to `individual` among
+    /// `window` random candidates.
+    pub fn closest_in_window(&self, individual: &I, population: &[I], window: usize, rng: &mut ThreadRng) -> usize {
+        (0..window.max(1))
+            .map(|_| rng.gen_range(0..population.len()))
+            .map(|index| (index, self.distance(individual, &population[index])))
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+            .map(|(index, _)| index)
+            .unwrap()
+    }
+
+    // Sharing divides a positive quality by the niche count, so scores are
+    // first measured as distance below the worst one.
+    fn shared(&self, population: &[I], scores: &[f64], radius: f64, alpha: f64) -> Vec<f64> {
+        let worst = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max) + 1e-9;
+        population
+            .iter()
+            .zip(scores)
+            .map(|(individual, &score)| {
+                let niche_count: f64 = population
+                    .iter()
+                    .map(|other| {
+                        let d = self.distance(individual, other);
+                        if d < radius {
+                            1.0 - (d / radius).powf(alpha)
+                        } else {
+                            0.0
+                        }
+                    })
+                    .sum();
+                worst - (worst - score) / niche_count.max(1.0)
+            })
+            .collect()
+    }
+
+    fn cleared(&self, population: &[I], scores: &[f64], radius: f64, capacity: usize) -> Vec<f64> {
+        let worst = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let mut order: Vec<usize> = (0..population.len()).collect();
+        order.sort_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap());
+
+        let mut cleared = scores.to_vec();
+        let mut done = vec![false; population.len()];
+        for (position, &winner) in order.iter().enumerate() {
+            if done[winner] {
+                continue;
+            }
+            done[winner] = true;
+            let mut winners = 1;
+            for &other in &order[position + 1..] {
+                if done[other] || self.distance(&population[winner], &population[other]) >= radius {
+                    continue;
+                }
+                done[other] = true;
+                if winners < capacity {
+                    winners += 1;
+                } else {
+                    cleared[other] = worst;
+                }
+            }
+        }
+        cleared
+    }
+}
+
+impl<I> Niching<I>
+where
+    I: RealVector,
+{
+    /// Uses the Euclidean distance between genomes.
+    pub fn euclidean(method: NichingMethod) -> Self {
+        Self::new(method, |a: &I, b: &I| euclidean_distance(a.genes(), b.genes()))
+    }
+}
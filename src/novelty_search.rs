@@ -0,0 +1,200 @@
+use rand::prelude::*;
+use std::sync::Arc;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{Individual, Crossover, Mutate, FitnessValue};
+use crate::observer::Observer;
+use crate::genetic_algorithm::GeneticAlgorithm;
+use crate::map_elites::BehaviourDescriptor;
+
+
+/// Archive of behaviours that were novel when they were encountered.
+#[derive(Clone, Debug)]
+pub struct NoveltyArchive {
+    pub k: usize,
+    pub threshold: f64,
+    pub capacity: usize,
+    behaviours: Vec<Vec<f64>>,
+}
+
+impl NoveltyArchive {
+    pub fn new(k: usize, threshold: f64, capacity: usize) -> Self {
+        Self {
+            k,
+            threshold,
+            capacity,
+            behaviours: Vec::new(),
+        }
+    }
+
+    pub fn behaviours(&self) -> &[Vec<f64>] {
+        &self.behaviours
+    }
+
+    pub fn len(&self) -> usize {
+        self.behaviours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.behaviours.is_empty()
+    }
+
+    /// Mean distance from `behaviour` to its `k` nearest neighbours among
+    /// `population` and the archived behaviours. The entry at `skip` in
+    /// `population` is the behaviour itself and is ignored.
+    pub fn novelty(&self, behaviour: &[f64], population: &[Vec<f64>], skip: usize) -> f64 {
+        let mut distances: Vec<f64> = population
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| index != skip)
+            .map(|(_, other)| euclidean_distance(behaviour, other))
+            .chain(self.behaviours.iter().map(|other| euclidean_distance(behaviour, other)))
+            .collect();
+
+        if distances.is_empty() {
+            return 0.0;
+        }
+
+        distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let k = self.k.clamp(1, distances.len());
+        distances[..k].iter().sum::<f64>() / k as f64
+    }
+
+    /// Stores `behaviour` if its novelty exceeds the threshold. Once the archive
+    /// is full, the oldest behaviour is evicted.
+    pub fn consider(&mut self, behaviour: Vec<f64>, novelty: f64) -> bool {
+        if novelty < self.threshold || self.capacity == 0 {
+            return false;
+        }
+        if self.behaviours.len() == self.capacity {
+            self.behaviours.remove(0);
+        }
+        self.behaviours.push(behaviour);
+        true
+    }
+}
+
+pub fn euclidean_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt()
+}
+
+/// Genetic algorithm that selects parents by novelty in behaviour space
+/// instead of by fitness, optionally blended with fitness.
+pub struct NoveltySearch<I>
+where
+    I: Individual,
+{
+    pub ga: GeneticAlgorithm<I>,
+    pub novelty_archive: NoveltyArchive,
+    /// Weight of fitness in the selection score: 0.0 is pure novelty search,
+    /// 1.0 is pure fitness-based search.
+    pub fitness_weight: f64,
+    descriptor: BehaviourDescriptor<I>,
+}
+
+impl<I> NoveltySearch<I>
+where
+    I: Individual,
+{
+    pub fn new<D>(ga: GeneticAlgorithm<I>, novelty_archive: NoveltyArchive, descriptor: D) -> Self
+    where
+        D: Fn(&I) -> Vec<f64> + Send + Sync + 'static,
+    {
+        Self {
+            ga,
+            novelty_archive,
+            fitness_weight: 0.0,
+            descriptor: Arc::new(descriptor),
+        }
+    }
+
+    pub fn with_fitness_weight(mut self, fitness_weight: f64) -> Self {
+        self.fitness_weight = fitness_weight;
+        self
+    }
+
+    /// Combines normalised novelty (higher is better) and normalised fitness
+    /// (lower is better) into one score in `[0, 1]` where lower is better.
+    fn blend(&self, novelty: &[f64], fitness: &[f64]) -> Vec<f64> {
+        let max_novelty = novelty.iter().cloned().fold(0.0, f64::max);
+        let min_fitness = fitness.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max_fitness = fitness.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let fitness_range = max_fitness - min_fitness;
+
+        novelty
+            .iter()
+            .zip(fitness)
+            .map(|(&n, &f)| {
+                let novelty_score = if max_novelty > 0.0 { 1.0 - n / max_novelty } else { 1.0 };
+                let fitness_score = if fitness_range > 0.0 { (f - min_fitness) / fitness_range } else { 0.0 };
+                (1.0 - self.fitness_weight) * novelty_score + self.fitness_weight * fitness_score
+            })
+            .collect()
+    }
+}
+
+impl<I> Optimizer<I> for NoveltySearch<I>
+where
+    I: Individual + Crossover + Mutate,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut rng = thread_rng();
+        let mut population: Vec<I> = self.ga.initialize_population(&mut rng);
+        let mut novelty_archive = self.novelty_archive.clone();
+
+        for observer in observers.iter_mut() {
+            observer.on_start();
+            observer.on_iteration(0, &population);
+        }
+
+        let mut rates = self.ga.initial_rates();
+        let mut success = None;
+        let mut fitness_scores: Vec<f64> = population.iter().map(|ind| ind.fitness().to_f64()).collect();
+
+        for generation in 1..=self.ga.generations {
+            let behaviours: Vec<Vec<f64>> = population.iter().map(|ind| (self.descriptor)(ind)).collect();
+            let novelty: Vec<f64> = behaviours
+                .iter()
+                .enumerate()
+                .map(|(index, behaviour)| novelty_archive.novelty(behaviour, &behaviours, index))
+                .collect();
+
+            for individual in &population {
+                archive.add(individual.clone());
+            }
+
+            for (behaviour, &score) in behaviours.into_iter().zip(&novelty) {
+                novelty_archive.consider(behaviour, score);
+            }
+
+            let mean_novelty = novelty.iter().sum::<f64>() / novelty.len().max(1) as f64;
+            for observer in observers.iter_mut() {
+                observer.on_iteration(generation, &population);
+                observer.on_metric(generation, "mean_novelty", mean_novelty);
+                observer.on_metric(generation, "novelty_archive_size", novelty_archive.len() as f64);
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+
+            let scores = self.blend(&novelty, &fitness_scores);
+            let pool = self.ga.select_mating_pool(&scores, &mut rng);
+
+            let progress = self.ga.progress(generation);
+            rates = self.ga.next_rates(rates, progress, success);
+            self.ga.report_rates(generation, self.ga.effective_rates(rates, &population), observers);
+
+            (population, fitness_scores, success) =
+                self.ga.crossover_and_mutate(&population, &fitness_scores, pool, rates, progress, &mut rng);
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
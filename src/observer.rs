@@ -8,6 +8,19 @@ where
     /// Called at each iteration with the current population.
     fn on_iteration(&mut self, iteration: usize, population: &[I]);
 
+    /// Called when an optimizer reports a named scalar statistic for an iteration.
+    fn on_metric(&mut self, _iteration: usize, _name: &str, _value: f64) {}
+
+    /// Called by optimizers that keep a best-so-far solution apart from their
+    /// current state, such as `SimulatedAnnealing`, whenever it improves.
+    fn on_best(&mut self, _iteration: usize, _best: &I) {}
+
+    /// Polled by optimizers after each iteration; returning true ends the run
+    /// early, after which `on_finish` is still called.
+    fn should_stop(&self) -> bool {
+        false
+    }
+
     /// Called at the end of the optimization process.
     fn on_finish(&mut self);
 }
@@ -4,6 +4,7 @@ use crate::archive::Archive;
 use crate::individuals::ParticleIndividual;
 use crate::observer::Observer;
 use crate::Individual;
+use crate::sampling::{Sampler, UniformSampler, opposition_based};
 use std::f64;
 
 pub struct ParticleSwarm<F>
@@ -17,6 +18,9 @@ where
     pub cognitive_coeff: f64,
     pub social_coeff: f64,
     pub fitness_function: F,
+    pub bounds: Vec<(f64, f64)>,
+    pub sampler: Box<dyn Sampler>,
+    pub opposition: bool,
 }
 
 impl<F> ParticleSwarm<F>
@@ -40,6 +44,37 @@ where
             cognitive_coeff,
             social_coeff,
             fitness_function,
+            bounds: vec![(-10.0, 10.0); dimensions],
+            sampler: Box::new(UniformSampler),
+            opposition: false,
+        }
+    }
+
+    /// Places the initial swarm inside `bounds` using `sampler` instead of
+    /// uniform draws from `[-10, 10)`.
+    pub fn with_sampler<S>(mut self, sampler: S, bounds: Vec<(f64, f64)>) -> Self
+    where
+        S: Sampler + 'static,
+    {
+        assert_eq!(bounds.len(), self.dimensions, "one bound is required per dimension");
+        self.sampler = Box::new(sampler);
+        self.bounds = bounds;
+        self
+    }
+
+    /// Enables opposition-based initialisation: each sampled position competes
+    /// with its opposite within the bounds and the fitter half forms the swarm.
+    pub fn with_opposition(mut self) -> Self {
+        self.opposition = true;
+        self
+    }
+
+    fn initial_positions(&self, rng: &mut ThreadRng) -> Vec<Vec<f64>> {
+        if self.opposition {
+            let fitness = |position: &[f64]| (self.fitness_function)(&position.to_vec());
+            opposition_based(self.sampler.as_ref(), self.swarm_size, &self.bounds, fitness, rng)
+        } else {
+            self.sampler.sample(self.swarm_size, &self.bounds, rng)
         }
     }
 }
@@ -55,8 +90,10 @@ where
     {
         let mut rng = thread_rng();
 
-        let mut particles: Vec<ParticleIndividual> = (0..self.swarm_size)
-            .map(|_| ParticleIndividual::new(self.dimensions))
+        let mut particles: Vec<ParticleIndividual> = self
+            .initial_positions(&mut rng)
+            .into_iter()
+            .map(ParticleIndividual::from_position)
             .collect();
 
         let mut global_best_position = vec![0.0; self.dimensions];
@@ -81,6 +118,7 @@ where
 
         for iteration in 0..self.iterations {
             for particle in &mut particles {
+                #[allow(clippy::needless_range_loop)]
                 for d in 0..self.dimensions {
                     let rp = rng.gen::<f64>();
                     let rg = rng.gen::<f64>();
@@ -109,6 +147,9 @@ where
             for observer in observers.iter_mut() {
                 observer.on_iteration(iteration, &particles);
             }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
         }
 
         for observer in observers.iter_mut() {
@@ -0,0 +1,312 @@
+use rand::prelude::*;
+use std::collections::HashSet;
+use crate::individual::{Permutation, CrossoverOperator, MutationOperator};
+
+
+/// Partially mapped crossover (PMX): a segment of the first parent is kept in
+/// place and the remaining cities follow the second parent via the mapping the
+/// segment defines.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PartiallyMapped;
+
+/// Order crossover (OX): a segment of the first parent is kept in place and
+/// the remaining cities are filled in the order they appear in the second
+/// parent, starting after the segment.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OrderCrossover;
+
+/// Cycle crossover (CX): every city keeps the position it has in one of the
+/// parents, alternating parents between position cycles.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CycleCrossover;
+
+/// Edge recombination crossover (ERX): builds a tour mostly from edges present
+/// in either parent, preferring cities with the fewest remaining neighbours.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EdgeRecombination;
+
+/// Exchanges two random positions.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SwapMutation;
+
+/// Moves a random city to another random position.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InsertionMutation;
+
+/// Reverses a random segment (a 2-opt move on a tour).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InversionMutation;
+
+/// Shuffles a random segment.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ScrambleMutation;
+
+impl<I> CrossoverOperator<I> for PartiallyMapped
+where
+    I: Permutation,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let child = pmx(parents[0].permutation(), parents[1].permutation(), rng);
+        with_permutation(parents[0], child)
+    }
+}
+
+impl<I> CrossoverOperator<I> for OrderCrossover
+where
+    I: Permutation,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let child = order_crossover(parents[0].permutation(), parents[1].permutation(), rng);
+        with_permutation(parents[0], child)
+    }
+}
+
+impl<I> CrossoverOperator<I> for CycleCrossover
+where
+    I: Permutation,
+{
+    fn crossover(&self, parents: &[&I], _rng: &mut ThreadRng) -> I {
+        let child = cycle_crossover(parents[0].permutation(), parents[1].permutation());
+        with_permutation(parents[0], child)
+    }
+}
+
+impl<I> CrossoverOperator<I> for EdgeRecombination
+where
+    I: Permutation,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let child = edge_recombination(parents[0].permutation(), parents[1].permutation(), rng);
+        with_permutation(parents[0], child)
+    }
+}
+
+impl<I> MutationOperator<I> for SwapMutation
+where
+    I: Permutation,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let mut permutation = individual.permutation().to_vec();
+        swap(&mut permutation, rng);
+        individual.set_permutation(permutation);
+    }
+}
+
+impl<I> MutationOperator<I> for InsertionMutation
+where
+    I: Permutation,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let mut permutation = individual.permutation().to_vec();
+        insertion(&mut permutation, rng);
+        individual.set_permutation(permutation);
+    }
+}
+
+impl<I> MutationOperator<I> for InversionMutation
+where
+    I: Permutation,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let mut permutation = individual.permutation().to_vec();
+        inversion(&mut permutation, rng);
+        individual.set_permutation(permutation);
+    }
+}
+
+impl<I> MutationOperator<I> for ScrambleMutation
+where
+    I: Permutation,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let mut permutation = individual.permutation().to_vec();
+        scramble(&mut permutation, rng);
+        individual.set_permutation(permutation);
+    }
+}
+
+pub fn pmx(parent1: &[usize], parent2: &[usize], rng: &mut ThreadRng) -> Vec<usize> {
+    let n = parent1.len();
+    let (start, end) = segment(n, rng);
+    let mut child: Vec<Option<usize>> = vec![None; n];
+    let mut in_segment = vec![false; n];
+    let position2 = positions(parent2);
+
+    for i in start..end {
+        child[i] = Some(parent1[i]);
+        in_segment[parent1[i]] = true;
+    }
+
+    for (i, &city) in parent2.iter().enumerate().take(end).skip(start) {
+        if in_segment[city] {
+            continue;
+        }
+        // Follow the mapping until it leads to a position outside the segment.
+        let mut position = i;
+        while (start..end).contains(&position) {
+            position = position2[parent1[position]];
+        }
+        child[position] = Some(city);
+    }
+
+    child
+        .into_iter()
+        .zip(parent2)
+        .map(|(city, &fallback)| city.unwrap_or(fallback))
+        .collect()
+}
+
+pub fn order_crossover(parent1: &[usize], parent2: &[usize], rng: &mut ThreadRng) -> Vec<usize> {
+    let n = parent1.len();
+    let (start, end) = segment(n, rng);
+    let mut child: Vec<Option<usize>> = vec![None; n];
+    let mut used = vec![false; n];
+
+    for i in start..end {
+        child[i] = Some(parent1[i]);
+        used[parent1[i]] = true;
+    }
+
+    let mut remaining = (0..n)
+        .map(|offset| parent2[(end + offset) % n])
+        .filter(|&city| !used[city]);
+    for offset in 0..n {
+        let position = (end + offset) % n;
+        if child[position].is_none() {
+            child[position] = remaining.next();
+        }
+    }
+
+    child.into_iter().map(|city| city.unwrap()).collect()
+}
+
+pub fn cycle_crossover(parent1: &[usize], parent2: &[usize]) -> Vec<usize> {
+    let n = parent1.len();
+    let position1 = positions(parent1);
+    let mut child: Vec<Option<usize>> = vec![None; n];
+    let mut from_first = true;
+
+    for start in 0..n {
+        if child[start].is_some() {
+            continue;
+        }
+        let source = if from_first { parent1 } else { parent2 };
+        let mut position = start;
+        loop {
+            child[position] = Some(source[position]);
+            position = position1[parent2[position]];
+            if position == start {
+                break;
+            }
+        }
+        from_first = !from_first;
+    }
+
+    child.into_iter().map(|city| city.unwrap()).collect()
+}
+
+pub fn edge_recombination(parent1: &[usize], parent2: &[usize], rng: &mut ThreadRng) -> Vec<usize> {
+    let n = parent1.len();
+    let mut neighbours: Vec<HashSet<usize>> = vec![HashSet::with_capacity(4); n];
+    for parent in [parent1, parent2] {
+        for i in 0..n {
+            let city = parent[i];
+            for adjacent in [parent[(i + n - 1) % n], parent[(i + 1) % n]] {
+                if adjacent != city {
+                    neighbours[city].insert(adjacent);
+                }
+            }
+        }
+    }
+
+    // Unvisited cities and the position of each in that list, for constant
+    // time removal and random fallback picks.
+    let mut unvisited: Vec<usize> = (0..n).collect();
+    let mut position: Vec<usize> = (0..n).collect();
+    let mut child = Vec::with_capacity(n);
+    let mut current = parent1[0];
+
+    while child.len() < n {
+        child.push(current);
+        let index = position[current];
+        unvisited.swap_remove(index);
+        if let Some(&moved) = unvisited.get(index) {
+            position[moved] = index;
+        }
+        let adjacent = std::mem::take(&mut neighbours[current]);
+        for &city in &adjacent {
+            neighbours[city].remove(&current);
+        }
+
+        current = if adjacent.is_empty() {
+            match unvisited.choose(rng) {
+                Some(&city) => city,
+                None => break,
+            }
+        } else {
+            let fewest = adjacent.iter().map(|&city| neighbours[city].len()).min().unwrap();
+            *adjacent
+                .iter()
+                .filter(|&&city| neighbours[city].len() == fewest)
+                .choose(rng)
+                .unwrap()
+        };
+    }
+
+    child
+}
+
+pub fn swap(permutation: &mut [usize], rng: &mut ThreadRng) {
+    if permutation.len() < 2 {
+        return;
+    }
+    let i = rng.gen_range(0..permutation.len());
+    let j = rng.gen_range(0..permutation.len());
+    permutation.swap(i, j);
+}
+
+pub fn insertion(permutation: &mut Vec<usize>, rng: &mut ThreadRng) {
+    if permutation.len() < 2 {
+        return;
+    }
+    let city = permutation.remove(rng.gen_range(0..permutation.len()));
+    let position = rng.gen_range(0..=permutation.len());
+    permutation.insert(position, city);
+}
+
+pub fn inversion(permutation: &mut [usize], rng: &mut ThreadRng) {
+    let (start, end) = segment(permutation.len(), rng);
+    permutation[start..end].reverse();
+}
+
+pub fn scramble(permutation: &mut [usize], rng: &mut ThreadRng) {
+    let (start, end) = segment(permutation.len(), rng);
+    permutation[start..end].shuffle(rng);
+}
+
+/// Random half-open range `start..end` with at least one element.
+fn segment(n: usize, rng: &mut ThreadRng) -> (usize, usize) {
+    if n == 0 {
+        return (0, 0);
+    }
+    let a = rng.gen_range(0..n);
+    let b = rng.gen_range(0..n);
+    (a.min(b), a.max(b) + 1)
+}
+
+fn positions(permutation: &[usize]) -> Vec<usize> {
+    let mut positions = vec![0; permutation.len()];
+    for (position, &city) in permutation.iter().enumerate() {
+        positions[city] = position;
+    }
+    positions
+}
+
+fn with_permutation<I>(parent: &I, permutation: Vec<usize>) -> I
+where
+    I: Permutation,
+{
+    let mut child = parent.clone();
+    child.set_permutation(permutation);
+    child
+}
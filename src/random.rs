@@ -0,0 +1,15 @@
+use rand::prelude::*;
+use std::f64::consts::PI;
+
+
+/// Draws from the standard normal distribution (Box-Muller transform).
+pub fn standard_normal(rng: &mut ThreadRng) -> f64 {
+    let u1: f64 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+/// Draws from the standard Cauchy distribution.
+pub fn standard_cauchy(rng: &mut ThreadRng) -> f64 {
+    (PI * (rng.gen::<f64>() - 0.5)).tan()
+}
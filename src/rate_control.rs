@@ -0,0 +1,229 @@
+use rand::prelude::*;
+use crate::individual::{Individual, Crossover, Mutate, RealVector, Permutation};
+use crate::random::standard_normal;
+
+
+/// Mutation and crossover probabilities in effect for a breeding step.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rates {
+    pub mutation: f64,
+    pub crossover: f64,
+}
+
+impl Rates {
+    pub fn new(mutation: f64, crossover: f64) -> Self {
+        Self { mutation, crossover }
+    }
+
+    pub fn mean(&self, other: &Rates) -> Rates {
+        Rates::new(0.5 * (self.mutation + other.mutation), 0.5 * (self.crossover + other.crossover))
+    }
+}
+
+/// How a genetic algorithm changes one of its rates during a run. The rate
+/// configured on the algorithm is the starting value.
+#[derive(Clone, Copy, Debug, Default)]
+pub enum RateControl {
+    /// The rate stays at its configured value.
+    #[default]
+    Fixed,
+    /// Moves linearly from the configured value to `end` over the run.
+    Linear { end: f64 },
+    /// Moves geometrically from the configured value to `end` over the run;
+    /// both must be positive.
+    Exponential { end: f64 },
+    /// Rechenberg-style success rule: after each generation the rate is
+    /// multiplied by `factor` if more than `target` of the offspring beat
+    /// their parent, and divided by it otherwise, staying within `[min, max]`.
+    SuccessBased {
+        target: f64,
+        factor: f64,
+        min: f64,
+        max: f64,
+    },
+}
+
+impl RateControl {
+    /// The one-fifth success rule with a factor of 1.2 on `[0.001, 1]`.
+    pub fn success_based() -> Self {
+        RateControl::SuccessBased {
+            target: 0.2,
+            factor: 1.2,
+            min: 0.001,
+            max: 1.0,
+        }
+    }
+
+    /// Returns the rate to use next. `success` is the share of offspring that
+    /// beat their parent in the previous generation, if known.
+    pub fn next(&self, initial: f64, current: f64, progress: f64, success: Option<f64>) -> f64 {
+        let progress = progress.clamp(0.0, 1.0);
+        match *self {
+            RateControl::Fixed => initial,
+            RateControl::Linear { end } => initial + (end - initial) * progress,
+            RateControl::Exponential { end } => initial * (end / initial).powf(progress),
+            RateControl::SuccessBased { target, factor, min, max } => match success {
+                Some(success) if success > target => (current * factor).clamp(min, max),
+                Some(_) => (current / factor).clamp(min, max),
+                None => current,
+            },
+        }
+    }
+
+    pub fn needs_success(&self) -> bool {
+        matches!(self, RateControl::SuccessBased { .. })
+    }
+}
+
+/// Individuals that carry their own mutation and crossover rates.
+pub trait AdaptiveRates: Individual {
+    fn rates(&self) -> Rates;
+    fn set_rates(&mut self, rates: Rates);
+}
+
+/// Reads, writes and varies the rates carried by individuals, so that a
+/// genetic algorithm can evolve them alongside the genomes.
+pub trait RateAdaptation<I>: Send + Sync
+where
+    I: Individual,
+{
+    fn rates(&self, individual: &I) -> Rates;
+    fn set_rates(&self, individual: &mut I, rates: Rates);
+    /// Varies the rates a child inherited before they are stored in it.
+    fn perturb(&self, rates: Rates, rng: &mut ThreadRng) -> Rates;
+}
+
+/// Settings for evolving rates encoded in the individuals. Children inherit
+/// the mean of their parents' rates (or their parent's, without crossover),
+/// which are then perturbed with a logit-normal step of size `learning_rate`.
+#[derive(Clone, Copy, Debug)]
+pub struct SelfAdaptation {
+    pub learning_rate: f64,
+}
+
+impl SelfAdaptation {
+    pub fn new(learning_rate: f64) -> Self {
+        Self { learning_rate }
+    }
+
+    // Perturbing in logit space keeps the rate inside (0, 1).
+    fn perturb_rate(&self, rate: f64, rng: &mut ThreadRng) -> f64 {
+        let rate = rate.clamp(1e-3, 1.0 - 1e-3);
+        1.0 / (1.0 + (1.0 - rate) / rate * (-self.learning_rate * standard_normal(rng)).exp())
+    }
+}
+
+impl<I> RateAdaptation<I> for SelfAdaptation
+where
+    I: AdaptiveRates,
+{
+    fn rates(&self, individual: &I) -> Rates {
+        individual.rates()
+    }
+
+    fn set_rates(&self, individual: &mut I, rates: Rates) {
+        individual.set_rates(rates);
+    }
+
+    fn perturb(&self, rates: Rates, rng: &mut ThreadRng) -> Rates {
+        Rates::new(
+            self.perturb_rate(rates.mutation, rng),
+            self.perturb_rate(rates.crossover, rng),
+        )
+    }
+}
+
+/// Wraps an individual together with its own rates so they can evolve with
+/// the genome. Fitness, crossover and mutation act on the wrapped individual.
+#[derive(Clone, Debug)]
+pub struct SelfAdaptive<I> {
+    pub individual: I,
+    pub rates: Rates,
+}
+
+impl<I> SelfAdaptive<I> {
+    pub fn new(individual: I, rates: Rates) -> Self {
+        Self { individual, rates }
+    }
+}
+
+impl<I> Default for SelfAdaptive<I>
+where
+    I: Default,
+{
+    /// Draws the mutation rate from `[0.01, 0.5)` and the crossover rate from
+    /// `[0.5, 1)` so the initial population covers a range of settings.
+    fn default() -> Self {
+        let mut rng = thread_rng();
+        let rates = Rates::new(rng.gen_range(0.01..0.5), rng.gen_range(0.5..1.0));
+        Self::new(I::default(), rates)
+    }
+}
+
+impl<I> Individual for SelfAdaptive<I>
+where
+    I: Individual,
+{
+    type Fitness = I::Fitness;
+
+    fn fitness(&self) -> Self::Fitness {
+        self.individual.fitness()
+    }
+}
+
+impl<I> AdaptiveRates for SelfAdaptive<I>
+where
+    I: Individual,
+{
+    fn rates(&self) -> Rates {
+        self.rates
+    }
+
+    fn set_rates(&mut self, rates: Rates) {
+        self.rates = rates;
+    }
+}
+
+impl<I> Crossover for SelfAdaptive<I>
+where
+    I: Crossover,
+{
+    fn crossover(&self, other: &Self, rng: &mut ThreadRng) -> Self {
+        Self::new(self.individual.crossover(&other.individual, rng), self.rates)
+    }
+}
+
+impl<I> Mutate for SelfAdaptive<I>
+where
+    I: Mutate,
+{
+    fn mutate(&mut self, rng: &mut ThreadRng) {
+        self.individual.mutate(rng);
+    }
+}
+
+impl<I> RealVector for SelfAdaptive<I>
+where
+    I: RealVector,
+{
+    fn genes(&self) -> &[f64] {
+        self.individual.genes()
+    }
+
+    fn genes_mut(&mut self) -> &mut Vec<f64> {
+        self.individual.genes_mut()
+    }
+}
+
+impl<I> Permutation for SelfAdaptive<I>
+where
+    I: Permutation,
+{
+    fn permutation(&self) -> &[usize] {
+        self.individual.permutation()
+    }
+
+    fn set_permutation(&mut self, permutation: Vec<usize>) {
+        self.individual.set_permutation(permutation);
+    }
+}
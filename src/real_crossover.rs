@@ -0,0 +1,378 @@
+use rand::prelude::*;
+use crate::individual::{RealVector, CrossoverOperator};
+use crate::random::standard_normal;
+
+
+/// Simulated binary crossover (Deb and Agrawal). Larger `eta` keeps children
+/// closer to their parents. With bounds, the bounded variant is used so
+/// children never leave the feasible box.
+#[derive(Clone, Debug)]
+pub struct Sbx {
+    pub eta: f64,
+    pub gene_probability: f64,
+    pub bounds: Option<Vec<(f64, f64)>>,
+}
+
+impl Sbx {
+    pub fn new(eta: f64) -> Self {
+        Self {
+            eta,
+            gene_probability: 0.5,
+            bounds: None,
+        }
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f64, f64)>) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    fn recombine(&self, x1: f64, x2: f64, bounds: Option<(f64, f64)>, rng: &mut ThreadRng) -> f64 {
+        if (x1 - x2).abs() < 1e-14 {
+            return x1;
+        }
+
+        let (y1, y2) = if x1 < x2 { (x1, x2) } else { (x2, x1) };
+        let u = rng.gen::<f64>();
+        let exponent = 1.0 / (self.eta + 1.0);
+        let spread = |beta: f64| {
+            let alpha = 2.0 - beta.powf(-(self.eta + 1.0));
+            if u <= 1.0 / alpha {
+                (u * alpha).powf(exponent)
+            } else {
+                (1.0 / (2.0 - u * alpha)).powf(exponent)
+            }
+        };
+
+        let (lower_beta, upper_beta) = match bounds {
+            Some((low, high)) => (1.0 + 2.0 * (y1 - low) / (y2 - y1), 1.0 + 2.0 * (high - y2) / (y2 - y1)),
+            None => (f64::INFINITY, f64::INFINITY),
+        };
+
+        // The child on parent 1's side of the midpoint is returned.
+        let child = if x1 <= x2 {
+            0.5 * ((y1 + y2) - spread(lower_beta) * (y2 - y1))
+        } else {
+            0.5 * ((y1 + y2) + spread(upper_beta) * (y2 - y1))
+        };
+
+        match bounds {
+            Some((low, high)) => child.clamp(low, high),
+            None => child,
+        }
+    }
+}
+
+impl<I> CrossoverOperator<I> for Sbx
+where
+    I: RealVector,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let genes = parents[0]
+            .genes()
+            .iter()
+            .zip(parents[1].genes())
+            .enumerate()
+            .map(|(i, (&x1, &x2))| {
+                if rng.gen::<f64>() < self.gene_probability {
+                    self.recombine(x1, x2, bound(&self.bounds, i), rng)
+                } else {
+                    x1
+                }
+            })
+            .collect();
+        child_with_genes(parents[0], genes)
+    }
+}
+
+/// Blend crossover: each gene is drawn uniformly from the parents' interval
+/// extended by `alpha` times its width on both sides.
+#[derive(Clone, Debug)]
+pub struct BlxAlpha {
+    pub alpha: f64,
+    pub bounds: Option<Vec<(f64, f64)>>,
+}
+
+impl BlxAlpha {
+    pub fn new(alpha: f64) -> Self {
+        Self { alpha, bounds: None }
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f64, f64)>) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+}
+
+impl<I> CrossoverOperator<I> for BlxAlpha
+where
+    I: RealVector,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let genes = parents[0]
+            .genes()
+            .iter()
+            .zip(parents[1].genes())
+            .enumerate()
+            .map(|(i, (&x1, &x2))| {
+                let (low, high) = (x1.min(x2), x1.max(x2));
+                let extent = self.alpha * (high - low);
+                let value = low - extent + rng.gen::<f64>() * (high - low + 2.0 * extent);
+                clamp(value, bound(&self.bounds, i))
+            })
+            .collect();
+        child_with_genes(parents[0], genes)
+    }
+}
+
+/// Whole arithmetic crossover: `weight * parent1 + (1 - weight) * parent2`.
+/// Without a fixed weight, a new one is drawn uniformly from `[0, 1)` per child.
+#[derive(Clone, Debug, Default)]
+pub struct Arithmetic {
+    pub weight: Option<f64>,
+}
+
+impl Arithmetic {
+    pub fn new(weight: f64) -> Self {
+        Self { weight: Some(weight) }
+    }
+
+    pub fn random() -> Self {
+        Self { weight: None }
+    }
+}
+
+impl<I> CrossoverOperator<I> for Arithmetic
+where
+    I: RealVector,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let weight = self.weight.unwrap_or_else(|| rng.gen::<f64>());
+        let genes = parents[0]
+            .genes()
+            .iter()
+            .zip(parents[1].genes())
+            .map(|(&x1, &x2)| weight * x1 + (1.0 - weight) * x2)
+            .collect();
+        child_with_genes(parents[0], genes)
+    }
+}
+
+/// Extended intermediate recombination: every gene gets its own weight drawn
+/// from `[-extension, 1 + extension)`.
+#[derive(Clone, Debug)]
+pub struct Intermediate {
+    pub extension: f64,
+    pub bounds: Option<Vec<(f64, f64)>>,
+}
+
+impl Intermediate {
+    pub fn new(extension: f64) -> Self {
+        Self { extension, bounds: None }
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f64, f64)>) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+}
+
+impl<I> CrossoverOperator<I> for Intermediate
+where
+    I: RealVector,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let genes = parents[0]
+            .genes()
+            .iter()
+            .zip(parents[1].genes())
+            .enumerate()
+            .map(|(i, (&x1, &x2))| {
+                let weight = -self.extension + rng.gen::<f64>() * (1.0 + 2.0 * self.extension);
+                clamp(weight * x1 + (1.0 - weight) * x2, bound(&self.bounds, i))
+            })
+            .collect();
+        child_with_genes(parents[0], genes)
+    }
+}
+
+/// Takes each gene from the second parent with probability `swap_probability`.
+#[derive(Clone, Copy, Debug)]
+pub struct UniformCrossover {
+    pub swap_probability: f64,
+}
+
+impl UniformCrossover {
+    pub fn new(swap_probability: f64) -> Self {
+        Self { swap_probability }
+    }
+}
+
+impl Default for UniformCrossover {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl<I> CrossoverOperator<I> for UniformCrossover
+where
+    I: RealVector,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let genes = parents[0]
+            .genes()
+            .iter()
+            .zip(parents[1].genes())
+            .map(|(&x1, &x2)| if rng.gen::<f64>() < self.swap_probability { x2 } else { x1 })
+            .collect();
+        child_with_genes(parents[0], genes)
+    }
+}
+
+/// Cuts the genome at `points` random positions and alternates between the
+/// parents' segments.
+#[derive(Clone, Copy, Debug)]
+pub struct MultiPoint {
+    pub points: usize,
+}
+
+impl MultiPoint {
+    pub fn new(points: usize) -> Self {
+        Self { points }
+    }
+}
+
+impl<I> CrossoverOperator<I> for MultiPoint
+where
+    I: RealVector,
+{
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let length = parents[0].genes().len();
+        let points = self.points.min(length.saturating_sub(1));
+        let mut cuts: Vec<usize> = rand::seq::index::sample(rng, length.saturating_sub(1), points)
+            .into_iter()
+            .map(|cut| cut + 1)
+            .collect();
+        cuts.sort_unstable();
+
+        let mut source = 0;
+        let mut next_cut = cuts.into_iter().peekable();
+        let genes = (0..length)
+            .map(|i| {
+                if next_cut.peek() == Some(&i) {
+                    next_cut.next();
+                    source = 1 - source;
+                }
+                parents[source].genes()[i]
+            })
+            .collect();
+        child_with_genes(parents[0], genes)
+    }
+}
+
+/// Unimodal normal distribution crossover (Ono and Kobayashi). The child is
+/// sampled around the midpoint of the first two parents: along their
+/// difference with deviation `sigma_xi`, and orthogonally with deviation
+/// `sigma_eta` scaled by the third parent's distance from that axis.
+#[derive(Clone, Debug)]
+pub struct Undx {
+    pub sigma_xi: f64,
+    pub sigma_eta: Option<f64>,
+    pub bounds: Option<Vec<(f64, f64)>>,
+}
+
+impl Undx {
+    /// Uses the recommended `sigma_xi = 0.5` and `sigma_eta = 0.35 / sqrt(n)`.
+    pub fn new() -> Self {
+        Self {
+            sigma_xi: 0.5,
+            sigma_eta: None,
+            bounds: None,
+        }
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f64, f64)>) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+}
+
+impl Default for Undx {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I> CrossoverOperator<I> for Undx
+where
+    I: RealVector,
+{
+    fn parents(&self) -> usize {
+        3
+    }
+
+    fn crossover(&self, parents: &[&I], rng: &mut ThreadRng) -> I {
+        let p1 = parents[0].genes();
+        let p2 = parents[1].genes();
+        let p3 = parents[2].genes();
+        let n = p1.len();
+        let sigma_eta = self.sigma_eta.unwrap_or(0.35 / (n as f64).sqrt());
+
+        let midpoint: Vec<f64> = p1.iter().zip(p2).map(|(a, b)| 0.5 * (a + b)).collect();
+        let direction: Vec<f64> = p1.iter().zip(p2).map(|(a, b)| b - a).collect();
+        let direction_norm = dot(&direction, &direction).sqrt();
+        let unit: Vec<f64> = if direction_norm > 0.0 {
+            direction.iter().map(|d| d / direction_norm).collect()
+        } else {
+            vec![0.0; n]
+        };
+
+        // Distance of the third parent from the line through the first two.
+        let offset: Vec<f64> = p3.iter().zip(p1).map(|(c, a)| c - a).collect();
+        let along = dot(&offset, &unit);
+        let distance = offset
+            .iter()
+            .zip(&unit)
+            .map(|(o, u)| (o - along * u).powi(2))
+            .sum::<f64>()
+            .sqrt();
+
+        // An isotropic normal vector with its component along the primary axis
+        // removed spans the orthogonal subspace.
+        let eta: Vec<f64> = (0..n).map(|_| sigma_eta * standard_normal(rng)).collect();
+        let eta_along = dot(&eta, &unit);
+        let xi = self.sigma_xi * standard_normal(rng);
+
+        let genes = (0..n)
+            .map(|i| {
+                let value = midpoint[i] + xi * direction[i] + distance * (eta[i] - eta_along * unit[i]);
+                clamp(value, bound(&self.bounds, i))
+            })
+            .collect();
+        child_with_genes(parents[0], genes)
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn bound(bounds: &Option<Vec<(f64, f64)>>, index: usize) -> Option<(f64, f64)> {
+    bounds.as_ref().and_then(|bounds| bounds.get(index).copied())
+}
+
+fn clamp(value: f64, bound: Option<(f64, f64)>) -> f64 {
+    match bound {
+        Some((low, high)) => value.clamp(low, high),
+        None => value,
+    }
+}
+
+fn child_with_genes<I>(parent: &I, genes: Vec<f64>) -> I
+where
+    I: RealVector,
+{
+    let mut child = parent.clone();
+    *child.genes_mut() = genes;
+    child
+}
@@ -0,0 +1,269 @@
+use rand::prelude::*;
+use crate::individual::{RealVector, MutationOperator};
+use crate::bounds::BoundHandling;
+use crate::random::{standard_normal, standard_cauchy};
+
+
+// Attempts at redrawing a perturbation under `BoundHandling::Resample` before
+// falling back to a uniform draw from the interval.
+const MAX_RESAMPLES: usize = 100;
+
+/// Adds `N(0, sigma^2)` noise to each selected gene.
+#[derive(Clone, Debug)]
+pub struct GaussianMutation {
+    pub sigma: f64,
+    /// Probability that a gene is mutated; `None` means `1 / n`.
+    pub gene_probability: Option<f64>,
+    pub bounds: Option<Vec<(f64, f64)>>,
+    pub handling: BoundHandling,
+}
+
+impl GaussianMutation {
+    pub fn new(sigma: f64) -> Self {
+        Self {
+            sigma,
+            gene_probability: None,
+            bounds: None,
+            handling: BoundHandling::Clip,
+        }
+    }
+
+    pub fn with_gene_probability(mut self, gene_probability: f64) -> Self {
+        self.gene_probability = Some(gene_probability);
+        self
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f64, f64)>, handling: BoundHandling) -> Self {
+        self.bounds = Some(bounds);
+        self.handling = handling;
+        self
+    }
+}
+
+impl<I> MutationOperator<I> for GaussianMutation
+where
+    I: RealVector,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let genes = individual.genes_mut();
+        let probability = gene_probability(self.gene_probability, genes.len());
+        for (i, gene) in genes.iter_mut().enumerate() {
+            if rng.gen::<f64>() < probability {
+                let bound = self.bounds.as_ref().and_then(|bounds| bounds.get(i).copied());
+                *gene = perturb(*gene, bound, self.handling, rng, |rng| self.sigma * standard_normal(rng));
+            }
+        }
+    }
+}
+
+/// Adds Cauchy-distributed noise with the given `scale`; its heavy tails make
+/// occasional long jumps.
+#[derive(Clone, Debug)]
+pub struct CauchyMutation {
+    pub scale: f64,
+    /// Probability that a gene is mutated; `None` means `1 / n`.
+    pub gene_probability: Option<f64>,
+    pub bounds: Option<Vec<(f64, f64)>>,
+    pub handling: BoundHandling,
+}
+
+impl CauchyMutation {
+    pub fn new(scale: f64) -> Self {
+        Self {
+            scale,
+            gene_probability: None,
+            bounds: None,
+            handling: BoundHandling::Clip,
+        }
+    }
+
+    pub fn with_gene_probability(mut self, gene_probability: f64) -> Self {
+        self.gene_probability = Some(gene_probability);
+        self
+    }
+
+    pub fn with_bounds(mut self, bounds: Vec<(f64, f64)>, handling: BoundHandling) -> Self {
+        self.bounds = Some(bounds);
+        self.handling = handling;
+        self
+    }
+}
+
+impl<I> MutationOperator<I> for CauchyMutation
+where
+    I: RealVector,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let genes = individual.genes_mut();
+        let probability = gene_probability(self.gene_probability, genes.len());
+        for (i, gene) in genes.iter_mut().enumerate() {
+            if rng.gen::<f64>() < probability {
+                let bound = self.bounds.as_ref().and_then(|bounds| bounds.get(i).copied());
+                *gene = perturb(*gene, bound, self.handling, rng, |rng| self.scale * standard_cauchy(rng));
+            }
+        }
+    }
+}
+
+/// Deb's bounded polynomial mutation. Larger `eta` gives smaller steps;
+/// mutated genes always stay within their bounds.
+#[derive(Clone, Debug)]
+pub struct PolynomialMutation {
+    pub eta: f64,
+    /// Probability that a gene is mutated; `None` means `1 / n`.
+    pub gene_probability: Option<f64>,
+    pub bounds: Vec<(f64, f64)>,
+}
+
+impl PolynomialMutation {
+    pub fn new(eta: f64, bounds: Vec<(f64, f64)>) -> Self {
+        Self {
+            eta,
+            gene_probability: None,
+            bounds,
+        }
+    }
+
+    pub fn with_gene_probability(mut self, gene_probability: f64) -> Self {
+        self.gene_probability = Some(gene_probability);
+        self
+    }
+}
+
+impl<I> MutationOperator<I> for PolynomialMutation
+where
+    I: RealVector,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let genes = individual.genes_mut();
+        let probability = gene_probability(self.gene_probability, genes.len());
+        let exponent = 1.0 / (self.eta + 1.0);
+
+        for (gene, &(low, high)) in genes.iter_mut().zip(&self.bounds) {
+            if rng.gen::<f64>() >= probability || high <= low {
+                continue;
+            }
+
+            let y = gene.clamp(low, high);
+            let delta1 = (y - low) / (high - low);
+            let delta2 = (high - y) / (high - low);
+            let u = rng.gen::<f64>();
+            let delta = if u < 0.5 {
+                let value = 2.0 * u + (1.0 - 2.0 * u) * (1.0 - delta1).powf(self.eta + 1.0);
+                value.powf(exponent) - 1.0
+            } else {
+                let value = 2.0 * (1.0 - u) + 2.0 * (u - 0.5) * (1.0 - delta2).powf(self.eta + 1.0);
+                1.0 - value.powf(exponent)
+            };
+            *gene = (y + delta * (high - low)).clamp(low, high);
+        }
+    }
+}
+
+/// Replaces each selected gene by a uniform draw from its bounds.
+#[derive(Clone, Debug)]
+pub struct UniformResetMutation {
+    /// Probability that a gene is mutated; `None` means `1 / n`.
+    pub gene_probability: Option<f64>,
+    pub bounds: Vec<(f64, f64)>,
+}
+
+impl UniformResetMutation {
+    pub fn new(bounds: Vec<(f64, f64)>) -> Self {
+        Self {
+            gene_probability: None,
+            bounds,
+        }
+    }
+
+    pub fn with_gene_probability(mut self, gene_probability: f64) -> Self {
+        self.gene_probability = Some(gene_probability);
+        self
+    }
+}
+
+impl<I> MutationOperator<I> for UniformResetMutation
+where
+    I: RealVector,
+{
+    fn mutate(&self, individual: &mut I, _progress: f64, rng: &mut ThreadRng) {
+        let genes = individual.genes_mut();
+        let probability = gene_probability(self.gene_probability, genes.len());
+        for (gene, &(low, high)) in genes.iter_mut().zip(&self.bounds) {
+            if rng.gen::<f64>() < probability {
+                *gene = rng.gen_range(low..=high);
+            }
+        }
+    }
+}
+
+/// Michalewicz's non-uniform mutation: steps shrink towards zero as the run
+/// progresses, at a rate set by `shape`.
+#[derive(Clone, Debug)]
+pub struct NonUniformMutation {
+    pub shape: f64,
+    /// Probability that a gene is mutated; `None` means `1 / n`.
+    pub gene_probability: Option<f64>,
+    pub bounds: Vec<(f64, f64)>,
+}
+
+impl NonUniformMutation {
+    pub fn new(shape: f64, bounds: Vec<(f64, f64)>) -> Self {
+        Self {
+            shape,
+            gene_probability: None,
+            bounds,
+        }
+    }
+
+    pub fn with_gene_probability(mut self, gene_probability: f64) -> Self {
+        self.gene_probability = Some(gene_probability);
+        self
+    }
+}
+
+impl<I> MutationOperator<I> for NonUniformMutation
+where
+    I: RealVector,
+{
+    fn mutate(&self, individual: &mut I, progress: f64, rng: &mut ThreadRng) {
+        let genes = individual.genes_mut();
+        let probability = gene_probability(self.gene_probability, genes.len());
+        let decay = (1.0 - progress.clamp(0.0, 1.0)).powf(self.shape);
+
+        for (gene, &(low, high)) in genes.iter_mut().zip(&self.bounds) {
+            if rng.gen::<f64>() < probability {
+                let step = |distance: f64, rng: &mut ThreadRng| distance * (1.0 - rng.gen::<f64>().powf(decay));
+                let y = gene.clamp(low, high);
+                *gene = if rng.gen::<bool>() {
+                    y + step(high - y, rng)
+                } else {
+                    y - step(y - low, rng)
+                };
+            }
+        }
+    }
+}
+
+fn gene_probability(probability: Option<f64>, genes: usize) -> f64 {
+    probability.unwrap_or(1.0 / genes.max(1) as f64)
+}
+
+fn perturb<F>(value: f64, bound: Option<(f64, f64)>, handling: BoundHandling, rng: &mut ThreadRng, draw: F) -> f64
+where
+    F: Fn(&mut ThreadRng) -> f64,
+{
+    let Some((low, high)) = bound else {
+        return value + draw(rng);
+    };
+
+    if handling == BoundHandling::Resample {
+        for _ in 0..MAX_RESAMPLES {
+            let candidate = value + draw(rng);
+            if (low..=high).contains(&candidate) {
+                return candidate;
+            }
+        }
+    }
+    handling.apply(value + draw(rng), (low, high), rng)
+}
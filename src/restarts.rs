@@ -0,0 +1,269 @@
+use std::sync::Arc;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{Individual, FitnessValue};
+use crate::observer::Observer;
+use crate::niching::Distance;
+
+
+pub type Factory<P> = Arc<dyn Fn(usize) -> P + Send + Sync>;
+
+/// Why a run of the wrapped optimizer ended. Reported to observers as the
+/// `restart_reason` metric with the discriminant as value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestartReason {
+    /// The optimizer used up its own iterations.
+    Completed = 0,
+    /// The best fitness of the run did not improve for `stagnation_window` iterations.
+    Stagnation = 1,
+    /// Population diversity fell below `diversity_threshold`.
+    DiversityCollapse = 2,
+}
+
+/// Meta-optimizer that restarts a wrapped optimizer whenever a run stagnates
+/// or its population loses diversity, until `max_restarts` or the shared
+/// evaluation budget is exhausted. All runs feed the same archive.
+///
+/// Each run is built by a factory from its population size, which is
+/// multiplied by `growth` at every restart. Observers see one continuous run;
+/// before each run they receive the `restart`, `population_size` and, from
+/// the second run on, `restart_reason` metrics.
+///
+/// The evaluation budget is approximate: it counts the individuals the
+/// optimizer reports to observers, not calls to `fitness`. Evaluations an
+/// optimizer makes without reporting them, such as local search steps or
+/// rejected candidates, are not counted, and a reported individual that was
+/// never evaluated, such as CMA-ES's start point, is. The stagnation and
+/// diversity checks score each reported individual once more; individuals
+/// with an expensive fitness should cache it.
+pub struct RestartWrapper<I, P>
+where
+    I: Individual,
+{
+    pub population_size: usize,
+    pub growth: f64,
+    pub max_restarts: usize,
+    pub max_evaluations: usize,
+    /// Iterations without improvement that end a run; 0 disables the check.
+    pub stagnation_window: usize,
+    /// Diversity below which a run ends; 0 disables the check.
+    pub diversity_threshold: f64,
+    /// Diversity is the mean pairwise distance under `distance` if set, and
+    /// the standard deviation of the fitness values otherwise.
+    distance: Option<Distance<I>>,
+    factory: Factory<P>,
+}
+
+impl<I, P> RestartWrapper<I, P>
+where
+    I: Individual,
+{
+    /// Restarts on 50 stagnant iterations, at most 10 times, without growing
+    /// the population.
+    pub fn new<F>(factory: F, population_size: usize, max_evaluations: usize) -> Self
+    where
+        F: Fn(usize) -> P + Send + Sync + 'static,
+    {
+        Self {
+            population_size,
+            growth: 1.0,
+            max_restarts: 10,
+            max_evaluations,
+            stagnation_window: 50,
+            diversity_threshold: 0.0,
+            distance: None,
+            factory: Arc::new(factory),
+        }
+    }
+
+    pub fn with_stagnation_window(mut self, window: usize) -> Self {
+        self.stagnation_window = window;
+        self
+    }
+
+    pub fn with_diversity_threshold(mut self, threshold: f64) -> Self {
+        self.diversity_threshold = threshold;
+        self
+    }
+
+    pub fn with_distance<D>(mut self, distance: D) -> Self
+    where
+        D: Fn(&I, &I) -> f64 + Send + Sync + 'static,
+    {
+        self.distance = Some(Arc::new(distance));
+        self
+    }
+
+    /// Multiplies the population size by `growth` at every restart, as in IPOP.
+    pub fn with_population_growth(mut self, growth: f64) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    pub fn with_max_restarts(mut self, max_restarts: usize) -> Self {
+        self.max_restarts = max_restarts;
+        self
+    }
+
+    fn population_size(&self, restart: usize) -> usize {
+        ((self.population_size as f64 * self.growth.powi(restart as i32)).round() as usize).max(1)
+    }
+}
+
+impl<I, P> Optimizer<I> for RestartWrapper<I, P>
+where
+    I: Individual,
+    P: Optimizer<I>,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        for observer in observers.iter_mut() {
+            observer.on_start();
+        }
+
+        let mut iteration = 0;
+        let mut evaluations = 0;
+        let mut reason = None;
+
+        for restart in 0..=self.max_restarts {
+            if evaluations >= self.max_evaluations || observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+
+            let population_size = self.population_size(restart);
+            for observer in observers.iter_mut() {
+                observer.on_metric(iteration, "restart", restart as f64);
+                observer.on_metric(iteration, "population_size", population_size as f64);
+                if let Some(reason) = reason {
+                    observer.on_metric(iteration, "restart_reason", reason as usize as f64);
+                }
+            }
+
+            let optimizer = (self.factory)(population_size);
+            let mut monitor = [Monitor {
+                wrapper: self,
+                observers: &mut *observers,
+                offset: iteration,
+                next: iteration,
+                evaluations,
+                best: f64::INFINITY,
+                stale: 0,
+                reason: None,
+            }];
+            optimizer.optimize(archive, &mut monitor);
+
+            let [monitor] = monitor;
+            iteration = monitor.next;
+            evaluations = monitor.evaluations;
+            reason = Some(monitor.reason.unwrap_or(RestartReason::Completed));
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
+
+// Observer handed to each run: forwards events with continuous iteration
+// numbers and asks the run to stop when it stagnates, loses diversity or
+// exhausts the shared budget.
+struct Monitor<'a, I, P, O>
+where
+    I: Individual,
+{
+    wrapper: &'a RestartWrapper<I, P>,
+    observers: &'a mut [O],
+    offset: usize,
+    next: usize,
+    evaluations: usize,
+    best: f64,
+    stale: usize,
+    reason: Option<RestartReason>,
+}
+
+impl<I, P, O> Monitor<'_, I, P, O>
+where
+    I: Individual,
+{
+    /// Diversity of `population`, whose fitness values are `scores`.
+    fn diversity(&self, population: &[I], scores: &[f64]) -> f64 {
+        let count = population.len();
+        match &self.wrapper.distance {
+            Some(distance) => {
+                let mut total = 0.0;
+                for (i, a) in population.iter().enumerate() {
+                    for b in &population[i + 1..] {
+                        total += distance(a, b);
+                    }
+                }
+                total / (count * (count - 1) / 2) as f64
+            }
+            None => {
+                let mean = scores.iter().sum::<f64>() / count as f64;
+                (scores.iter().map(|score| (score - mean).powi(2)).sum::<f64>() / count as f64).sqrt()
+            }
+        }
+    }
+}
+
+impl<I, P, O> Observer<I> for Monitor<'_, I, P, O>
+where
+    I: Individual,
+    O: Observer<I>,
+{
+    fn on_start(&mut self) {}
+
+    fn on_iteration(&mut self, iteration: usize, population: &[I]) {
+        let iteration = self.offset + iteration;
+        self.next = self.next.max(iteration + 1);
+        self.evaluations += population.len();
+        for observer in self.observers.iter_mut() {
+            observer.on_iteration(iteration, population);
+        }
+
+        let checks_stagnation = self.wrapper.stagnation_window > 0;
+        let checks_diversity = self.wrapper.diversity_threshold > 0.0 && population.len() > 1;
+        if !checks_stagnation && !checks_diversity {
+            return;
+        }
+
+        // Each reported individual is scored once for both checks.
+        let scores: Vec<f64> = population.iter().map(|ind| ind.fitness().to_f64()).collect();
+        let best = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+        if best < self.best {
+            self.best = best;
+            self.stale = 0;
+        } else {
+            self.stale += 1;
+        }
+
+        if checks_stagnation && self.stale >= self.wrapper.stagnation_window {
+            self.reason = Some(RestartReason::Stagnation);
+        } else if checks_diversity && self.diversity(population, &scores) < self.wrapper.diversity_threshold {
+            self.reason = Some(RestartReason::DiversityCollapse);
+        }
+    }
+
+    fn on_metric(&mut self, iteration: usize, name: &str, value: f64) {
+        for observer in self.observers.iter_mut() {
+            observer.on_metric(self.offset + iteration, name, value);
+        }
+    }
+
+    fn on_best(&mut self, iteration: usize, best: &I) {
+        for observer in self.observers.iter_mut() {
+            observer.on_best(self.offset + iteration, best);
+        }
+    }
+
+    fn should_stop(&self) -> bool {
+        self.reason.is_some()
+            || self.evaluations >= self.wrapper.max_evaluations
+            || self.observers.iter().any(|observer| observer.should_stop())
+    }
+
+    fn on_finish(&mut self) {}
+}
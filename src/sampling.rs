@@ -0,0 +1,237 @@
+use rand::prelude::*;
+
+
+/// Strategy for placing initial points inside a box given by per-dimension
+/// `(low, high)` bounds.
+pub trait Sampler: Send + Sync {
+    fn sample(&self, count: usize, bounds: &[(f64, f64)], rng: &mut ThreadRng) -> Vec<Vec<f64>>;
+}
+
+/// Independent uniform draws; the behaviour of the default initializers.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct UniformSampler;
+
+impl Sampler for UniformSampler {
+    fn sample(&self, count: usize, bounds: &[(f64, f64)], rng: &mut ThreadRng) -> Vec<Vec<f64>> {
+        (0..count)
+            .map(|_| bounds.iter().map(|&(low, high)| low + rng.gen::<f64>() * (high - low)).collect())
+            .collect()
+    }
+}
+
+/// Latin hypercube sampling: every dimension is split into `count` equal
+/// strata and each stratum receives exactly one point.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LatinHypercube;
+
+impl Sampler for LatinHypercube {
+    fn sample(&self, count: usize, bounds: &[(f64, f64)], rng: &mut ThreadRng) -> Vec<Vec<f64>> {
+        let mut points = vec![Vec::with_capacity(bounds.len()); count];
+        for &(low, high) in bounds {
+            let mut strata: Vec<usize> = (0..count).collect();
+            strata.shuffle(rng);
+            for (point, stratum) in points.iter_mut().zip(strata) {
+                let unit = (stratum as f64 + rng.gen::<f64>()) / count as f64;
+                point.push(low + unit * (high - low));
+            }
+        }
+        points
+    }
+}
+
+/// Halton low-discrepancy sequence using the first `d` primes as bases. The
+/// sequence starts at index 1 to skip the origin. With `shift` enabled, a
+/// random Cranley-Patterson rotation gives each call a different point set.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Halton {
+    pub shift: bool,
+}
+
+impl Halton {
+    pub fn new() -> Self {
+        Self { shift: false }
+    }
+
+    pub fn shifted() -> Self {
+        Self { shift: true }
+    }
+}
+
+impl Sampler for Halton {
+    fn sample(&self, count: usize, bounds: &[(f64, f64)], rng: &mut ThreadRng) -> Vec<Vec<f64>> {
+        let bases = primes(bounds.len());
+        let units = (1..=count)
+            .map(|index| bases.iter().map(|&base| radical_inverse(index, base)).collect())
+            .collect();
+        scale(units, bounds, self.shift, rng)
+    }
+}
+
+/// Number of dimensions covered by the built-in Sobol direction numbers.
+pub const SOBOL_MAX_DIMENSIONS: usize = 21;
+
+// Primitive polynomial degree `s`, coefficients `a` and initial direction
+// numbers `m` for dimensions 2 and up, from Joe and Kuo (2008).
+const SOBOL_DIRECTIONS: [(u32, u32, &[u32]); SOBOL_MAX_DIMENSIONS - 1] = [
+    (1, 0, &[1]),
+    (2, 1, &[1, 3]),
+    (3, 1, &[1, 3, 1]),
+    (3, 2, &[1, 1, 1]),
+    (4, 1, &[1, 1, 3, 3]),
+    (4, 4, &[1, 3, 5, 13]),
+    (5, 2, &[1, 1, 5, 5, 17]),
+    (5, 4, &[1, 1, 5, 5, 5]),
+    (5, 7, &[1, 1, 7, 11, 19]),
+    (5, 11, &[1, 1, 5, 1, 1]),
+    (5, 13, &[1, 1, 1, 3, 11]),
+    (5, 14, &[1, 3, 5, 5, 31]),
+    (6, 1, &[1, 3, 3, 9, 7, 49]),
+    (6, 13, &[1, 1, 1, 15, 21, 21]),
+    (6, 16, &[1, 3, 1, 13, 27, 49]),
+    (6, 19, &[1, 1, 1, 15, 7, 5]),
+    (6, 22, &[1, 3, 1, 15, 13, 25]),
+    (6, 25, &[1, 1, 5, 5, 19, 61]),
+    (7, 1, &[1, 3, 7, 11, 23, 15, 103]),
+    (7, 4, &[1, 3, 7, 13, 13, 15, 69]),
+];
+
+/// Sobol low-discrepancy sequence for up to `SOBOL_MAX_DIMENSIONS` dimensions,
+/// skipping the initial all-zero point. `shift` applies a random
+/// Cranley-Patterson rotation as for `Halton`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Sobol {
+    pub shift: bool,
+}
+
+impl Sobol {
+    pub fn new() -> Self {
+        Self { shift: false }
+    }
+
+    pub fn shifted() -> Self {
+        Self { shift: true }
+    }
+
+    fn direction_numbers(dimension: usize) -> [u32; 32] {
+        let mut v = [0u32; 32];
+        if dimension == 0 {
+            for (k, value) in v.iter_mut().enumerate() {
+                *value = 1 << (31 - k);
+            }
+            return v;
+        }
+
+        let (s, a, m) = SOBOL_DIRECTIONS[dimension - 1];
+        let s = s as usize;
+        for k in 0..s {
+            v[k] = m[k] << (31 - k);
+        }
+        for k in s..32 {
+            let mut value = v[k - s] ^ (v[k - s] >> s);
+            for i in 1..s {
+                if (a >> (s - 1 - i)) & 1 == 1 {
+                    value ^= v[k - i];
+                }
+            }
+            v[k] = value;
+        }
+        v
+    }
+}
+
+impl Sampler for Sobol {
+    fn sample(&self, count: usize, bounds: &[(f64, f64)], rng: &mut ThreadRng) -> Vec<Vec<f64>> {
+        assert!(
+            bounds.len() <= SOBOL_MAX_DIMENSIONS,
+            "Sobol sampling supports at most {} dimensions",
+            SOBOL_MAX_DIMENSIONS
+        );
+
+        let directions: Vec<[u32; 32]> = (0..bounds.len()).map(Self::direction_numbers).collect();
+        let mut state = vec![0u32; bounds.len()];
+        let mut units = Vec::with_capacity(count);
+
+        // Gray-code construction: point n differs from point n - 1 by the
+        // direction number indexed by the lowest zero bit of n - 1.
+        for n in 1..=count {
+            let bit = (n - 1).trailing_ones() as usize;
+            for (x, v) in state.iter_mut().zip(&directions) {
+                *x ^= v[bit];
+            }
+            units.push(state.iter().map(|&x| x as f64 / 4_294_967_296.0).collect());
+        }
+
+        scale(units, bounds, self.shift, rng)
+    }
+}
+
+/// Opposition-based learning: draws `count` points with `sampler`, adds the
+/// opposite point `low + high - x` of each, and keeps the `count` points with
+/// the lowest `fitness`.
+pub fn opposition_based<S, F>(
+    sampler: &S,
+    count: usize,
+    bounds: &[(f64, f64)],
+    fitness: F,
+    rng: &mut ThreadRng,
+) -> Vec<Vec<f64>>
+where
+    S: Sampler + ?Sized,
+    F: Fn(&[f64]) -> f64,
+{
+    let points = sampler.sample(count, bounds, rng);
+    let opposites: Vec<Vec<f64>> = points.iter().map(|point| opposite(point, bounds)).collect();
+
+    let mut candidates: Vec<(f64, Vec<f64>)> = points
+        .into_iter()
+        .chain(opposites)
+        .map(|point| (fitness(&point), point))
+        .collect();
+    candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    candidates.into_iter().take(count).map(|(_, point)| point).collect()
+}
+
+pub fn opposite(point: &[f64], bounds: &[(f64, f64)]) -> Vec<f64> {
+    point.iter().zip(bounds).map(|(&x, &(low, high))| low + high - x).collect()
+}
+
+fn scale(units: Vec<Vec<f64>>, bounds: &[(f64, f64)], shift: bool, rng: &mut ThreadRng) -> Vec<Vec<f64>> {
+    let offsets: Vec<f64> = bounds
+        .iter()
+        .map(|_| if shift { rng.gen::<f64>() } else { 0.0 })
+        .collect();
+
+    units
+        .into_iter()
+        .map(|unit| {
+            unit.iter()
+                .zip(bounds)
+                .zip(&offsets)
+                .map(|((&u, &(low, high)), &offset)| low + (u + offset).fract() * (high - low))
+                .collect()
+        })
+        .collect()
+}
+
+fn radical_inverse(mut index: usize, base: usize) -> f64 {
+    let mut result = 0.0;
+    let mut fraction = 1.0 / base as f64;
+    while index > 0 {
+        result += (index % base) as f64 * fraction;
+        index /= base;
+        fraction /= base as f64;
+    }
+    result
+}
+
+fn primes(count: usize) -> Vec<usize> {
+    let mut primes: Vec<usize> = Vec::with_capacity(count);
+    let mut candidate = 2;
+    while primes.len() < count {
+        if primes.iter().all(|&p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    primes
+}
@@ -0,0 +1,197 @@
+use rand::prelude::*;
+
+
+/// Parent selection scheme used by `GeneticAlgorithm`.
+///
+/// Implementations receive one score per individual, where lower is better
+/// (the same convention as `Archive`), and return the indices of the selected
+/// individuals. Scores may be negative.
+pub trait Selection: Send + Sync {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize>;
+}
+
+/// Fitness-proportional selection. Scores are shifted so the worst individual
+/// has weight zero and the best has the largest weight.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RouletteWheel;
+
+impl Selection for RouletteWheel {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        let weights = shifted_weights(scores);
+        (0..count).map(|_| spin(&weights, rng.gen::<f64>())).collect()
+    }
+}
+
+/// Picks the best of `size` individuals drawn uniformly with replacement.
+#[derive(Clone, Copy, Debug)]
+pub struct Tournament {
+    pub size: usize,
+}
+
+impl Tournament {
+    pub fn new(size: usize) -> Self {
+        Self { size }
+    }
+}
+
+impl Selection for Tournament {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        (0..count)
+            .map(|_| {
+                (0..self.size.max(1))
+                    .map(|_| rng.gen_range(0..scores.len()))
+                    .min_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap())
+                    .unwrap()
+            })
+            .collect()
+    }
+}
+
+/// Linear ranking. `selection_pressure` in `[1.0, 2.0]` is the expected number
+/// of copies of the best individual; 1.0 is uniform selection.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearRank {
+    pub selection_pressure: f64,
+}
+
+impl LinearRank {
+    pub fn new(selection_pressure: f64) -> Self {
+        Self { selection_pressure }
+    }
+}
+
+impl Selection for LinearRank {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        let n = scores.len() as f64;
+        let s = self.selection_pressure;
+        let weights = rank_weights(scores, |rank| {
+            if n > 1.0 {
+                (2.0 - s) / n + 2.0 * (n - 1.0 - rank as f64) * (s - 1.0) / (n * (n - 1.0))
+            } else {
+                1.0
+            }
+        });
+        (0..count).map(|_| spin(&weights, rng.gen::<f64>())).collect()
+    }
+}
+
+/// Exponential ranking: the individual of rank `r` (0 is best) has weight `base^r`,
+/// with `base` in `(0.0, 1.0)`.
+#[derive(Clone, Copy, Debug)]
+pub struct ExponentialRank {
+    pub base: f64,
+}
+
+impl ExponentialRank {
+    pub fn new(base: f64) -> Self {
+        Self { base }
+    }
+}
+
+impl Selection for ExponentialRank {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        let weights = rank_weights(scores, |rank| self.base.powi(rank as i32));
+        (0..count).map(|_| spin(&weights, rng.gen::<f64>())).collect()
+    }
+}
+
+/// Stochastic universal sampling: fitness-proportional like `RouletteWheel`,
+/// but all `count` pointers come from a single spin, which minimises spread.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct StochasticUniversalSampling;
+
+impl Selection for StochasticUniversalSampling {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        let weights = shifted_weights(scores);
+        let start = rng.gen::<f64>() / count as f64;
+        (0..count)
+            .map(|i| spin(&weights, start + i as f64 / count as f64))
+            .collect()
+    }
+}
+
+/// Selects uniformly among the best `proportion` of the population.
+#[derive(Clone, Copy, Debug)]
+pub struct Truncation {
+    pub proportion: f64,
+}
+
+impl Truncation {
+    pub fn new(proportion: f64) -> Self {
+        Self { proportion }
+    }
+}
+
+impl Selection for Truncation {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        let ranked = ranking(scores);
+        let keep = ((scores.len() as f64 * self.proportion).ceil() as usize).clamp(1, scores.len());
+        (0..count).map(|_| ranked[rng.gen_range(0..keep)]).collect()
+    }
+}
+
+/// Boltzmann selection: weight `exp(-(score - best) / temperature)`. Low
+/// temperatures approach greedy selection, high ones uniform selection.
+#[derive(Clone, Copy, Debug)]
+pub struct Boltzmann {
+    pub temperature: f64,
+}
+
+impl Boltzmann {
+    pub fn new(temperature: f64) -> Self {
+        Self { temperature }
+    }
+}
+
+impl Selection for Boltzmann {
+    fn select(&self, scores: &[f64], count: usize, rng: &mut ThreadRng) -> Vec<usize> {
+        let best = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+        let weights: Vec<f64> = scores
+            .iter()
+            .map(|&score| (-(score - best) / self.temperature).exp())
+            .collect();
+        (0..count).map(|_| spin(&weights, rng.gen::<f64>())).collect()
+    }
+}
+
+/// Indices sorted from best (lowest score) to worst.
+fn ranking(scores: &[f64]) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..scores.len()).collect();
+    indices.sort_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap());
+    indices
+}
+
+fn rank_weights<F>(scores: &[f64], weight: F) -> Vec<f64>
+where
+    F: Fn(usize) -> f64,
+{
+    let mut weights = vec![0.0; scores.len()];
+    for (rank, index) in ranking(scores).into_iter().enumerate() {
+        weights[index] = weight(rank);
+    }
+    weights
+}
+
+fn shifted_weights(scores: &[f64]) -> Vec<f64> {
+    let worst = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    scores.iter().map(|&score| worst - score).collect()
+}
+
+/// Maps `pick` in `[0, 1)` to an index with probability proportional to
+/// `weights`. Falls back to uniform selection when all weights are zero.
+fn spin(weights: &[f64], pick: f64) -> usize {
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 || !total.is_finite() {
+        return ((pick * weights.len() as f64) as usize).min(weights.len() - 1);
+    }
+
+    let target = pick * total;
+    let mut current = 0.0;
+    for (index, &weight) in weights.iter().enumerate() {
+        current += weight;
+        if current > target {
+            return index;
+        }
+    }
+    weights.len() - 1
+}
@@ -0,0 +1,117 @@
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use crate::archive::Archive;
+use crate::individual::{Individual, FitnessValue};
+
+
+/// Handle to an archive shared between threads.
+///
+/// Cloning the handle yields another handle to the same underlying archive, so
+/// each thread can pass its own handle to `Optimizer::optimize`. Because
+/// `get_best` has to return a reference, every handle caches the global best
+/// solution. The cache is re-read on `refresh` and whenever an `add` beats the
+/// cached best, so other threads' improvements may show up late; use `best`
+/// or `snapshot` for an up-to-date view.
+pub struct SharedArchive<A>
+where
+    A: Archive,
+{
+    inner: Arc<RwLock<A>>,
+    best: Option<A::Solution>,
+    best_score: Option<f64>,
+}
+
+impl<A> SharedArchive<A>
+where
+    A: Archive,
+{
+    pub fn new(archive: A) -> Self {
+        let best = archive.get_best().cloned();
+        let mut shared = Self {
+            inner: Arc::new(RwLock::new(archive)),
+            best: None,
+            best_score: None,
+        };
+        shared.cache(best);
+        shared
+    }
+
+    /// Returns a clone of the current global best solution.
+    pub fn best(&self) -> Option<A::Solution> {
+        self.read().get_best().cloned()
+    }
+
+    /// Clones the archive's current solutions. The read lock is held only for
+    /// the duration of the copy.
+    pub fn snapshot(&self) -> Vec<A::Solution> {
+        self.read().solutions().into_iter().cloned().collect()
+    }
+
+    /// Updates this handle's cached best from the shared archive.
+    pub fn refresh(&mut self) {
+        let best = self.best();
+        self.cache(best);
+    }
+
+    /// Returns the wrapped archive if this is the last handle to it.
+    pub fn into_inner(self) -> Option<A> {
+        Arc::try_unwrap(self.inner)
+            .ok()
+            .map(|lock| lock.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner()))
+    }
+
+    // A panic in another optimizer thread must not take the shared archive down
+    // with it, so poisoned locks are recovered rather than propagated.
+    fn read(&self) -> RwLockReadGuard<'_, A> {
+        self.inner.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, A> {
+        self.inner.write().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn cache(&mut self, best: Option<A::Solution>) {
+        self.best_score = best.as_ref().map(|solution| solution.fitness().to_f64());
+        self.best = best;
+    }
+}
+
+impl<A> Clone for SharedArchive<A>
+where
+    A: Archive,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            best: self.best.clone(),
+            best_score: self.best_score,
+        }
+    }
+}
+
+impl<A> Archive for SharedArchive<A>
+where
+    A: Archive,
+{
+    type Solution = A::Solution;
+    type Fitness = A::Fitness;
+
+    fn add(&mut self, solution: Self::Solution) {
+        // Scored before taking the lock; the best is only cloned under it when
+        // it may have changed.
+        let score = solution.fitness().to_f64();
+        let improves = self.best_score.is_none_or(|best| score < best);
+        let best = {
+            let mut archive = self.write();
+            archive.add(solution);
+            if !improves {
+                return;
+            }
+            archive.get_best().cloned()
+        };
+        self.cache(best);
+    }
+
+    fn get_best(&self) -> Option<&Self::Solution> {
+        self.best.as_ref()
+    }
+}
@@ -3,31 +3,155 @@ use crate::optimizer::Optimizer;
 use crate::archive::Archive;
 use crate::individual::{Individual, Neighbor, FitnessValue};
 use crate::observer::Observer;
+use crate::initializer::Initializer;
+use crate::cooling::{CoolingSchedule, ChainStatistics, calibrate_temperature, calibrate_threshold};
+use crate::acceptance::{Acceptance, Acceptor};
 
 
-pub struct SimulatedAnnealing {
+pub struct SimulatedAnnealing<I>
+where
+    I: Individual,
+{
     pub initial_temp: f64,
-    pub cooling_rate: f64,
+    pub schedule: CoolingSchedule,
+    pub acceptance: Acceptance,
+    /// Moves made at each temperature before the schedule is applied.
+    pub chain_length: usize,
+    /// When set, `initial_temp` is replaced by the temperature that accepts
+    /// this share of uphill moves from the starting state.
+    pub initial_acceptance: Option<f64>,
+    /// Neighbours of the starting state sampled for the calibration.
+    pub calibration_samples: usize,
+    /// Non-improving moves after which the walk jumps back to the best
+    /// solution found so far; 0 never restarts.
+    pub restart_after: usize,
     pub iterations: usize,
+    pub initializer: Initializer<I>,
 }
 
-impl SimulatedAnnealing {
+impl<I> SimulatedAnnealing<I>
+where
+    I: Individual + Default,
+{
     pub fn new(
         initial_temp: f64,
         cooling_rate: f64,
         iterations: usize,
+    ) -> Self {
+        Self::from_initializer(Initializer::default(), initial_temp, cooling_rate, iterations)
+    }
+}
+
+impl<I> SimulatedAnnealing<I>
+where
+    I: Individual,
+{
+    /// Creates an annealer that starts from the first seed of `initializer`, or
+    /// from a generated solution if it has no seeds. It cools geometrically at
+    /// `cooling_rate` after every move.
+    pub fn from_initializer(
+        initializer: Initializer<I>,
+        initial_temp: f64,
+        cooling_rate: f64,
+        iterations: usize,
     ) -> Self {
         Self {
             initial_temp,
-            cooling_rate,
+            schedule: CoolingSchedule::Geometric { rate: cooling_rate },
+            acceptance: Acceptance::Metropolis,
+            chain_length: 1,
+            initial_acceptance: None,
+            calibration_samples: 100,
+            restart_after: 0,
             iterations,
+            initializer,
+        }
+    }
+
+    /// Rate of a geometric schedule, or `None` for any other schedule.
+    #[deprecated(note = "the rate is part of `schedule`; match on `CoolingSchedule::Geometric` instead")]
+    pub fn cooling_rate(&self) -> Option<f64> {
+        match self.schedule {
+            CoolingSchedule::Geometric { rate } => Some(rate),
+            _ => None,
         }
     }
+
+    pub fn with_schedule(mut self, schedule: CoolingSchedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Replaces the Metropolis test, turning the annealer into threshold
+    /// accepting, great deluge, record-to-record travel, late acceptance or
+    /// hill climbing. The temperature is only used by the Metropolis test and
+    /// as the threshold of threshold accepting. The great deluge reports its
+    /// water level as the `water_level` metric.
+    pub fn with_acceptance(mut self, acceptance: Acceptance) -> Self {
+        self.acceptance = acceptance;
+        self
+    }
+
+    /// Starts the walk from `start` instead of a generated solution.
+    pub fn with_start(mut self, start: I) -> Self {
+        self.initializer = self.initializer.with_seeds(vec![start]);
+        self
+    }
+
+    /// Returns to the best solution found so far after `moves` consecutive
+    /// moves that did not improve on it. Each restart is reported as the
+    /// `restart_from_best` metric with the number of restarts so far.
+    pub fn with_restart_from_best(mut self, moves: usize) -> Self {
+        self.restart_after = moves;
+        self
+    }
+
+    /// Makes `length` moves at each temperature.
+    pub fn with_chain_length(mut self, length: usize) -> Self {
+        self.chain_length = length.max(1);
+        self
+    }
+
+    /// Calibrates the initial temperature before the run from `samples`
+    /// random neighbours of the starting state so that uphill moves are
+    /// accepted with probability `acceptance`, e.g. 0.8. The chosen value is
+    /// reported as the `initial_temperature` metric. With threshold accepting
+    /// the threshold is set so that this share of the sampled uphill moves
+    /// passes. Rules that ignore the temperature, and runs where no sampled
+    /// move is uphill, keep `initial_temp`.
+    pub fn with_initial_acceptance(mut self, acceptance: f64, samples: usize) -> Self {
+        self.initial_acceptance = Some(acceptance);
+        self.calibration_samples = samples.max(1);
+        self
+    }
+}
+
+impl<I> SimulatedAnnealing<I>
+where
+    I: Individual + Neighbor,
+{
+    fn initial_temperature(&self, start: &I, rng: &mut ThreadRng) -> f64 {
+        let Some(acceptance) = self.initial_acceptance else {
+            return self.initial_temp;
+        };
+        let mut start = start.clone();
+        let score = start.fitness().to_f64();
+        let calibrate = match self.acceptance {
+            Acceptance::Metropolis => calibrate_temperature,
+            Acceptance::ThresholdAccepting => calibrate_threshold,
+            // The other rules ignore the temperature.
+            _ => return self.initial_temp,
+        };
+        let deltas: Vec<f64> = (0..self.calibration_samples)
+            .map(|_| start.neighbor(rng).fitness().to_f64() - score)
+            .collect();
+        calibrate(&deltas, acceptance).unwrap_or(self.initial_temp)
+    }
 }
 
-impl<I> Optimizer<I> for SimulatedAnnealing
+impl<I> Optimizer<I> for SimulatedAnnealing<I>
 where
-    I: Individual + Neighbor + Default,
+    I: Individual + Neighbor,
     I::Fitness: PartialOrd + FitnessValue,
 {
     fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
@@ -36,29 +160,102 @@ where
         O: Observer<I>,
     {
         let mut rng = thread_rng();
-        let mut current_state = I::default();
-        let mut current_temp = self.initial_temp;
+        let mut current_state = self.initializer.individual(&mut rng);
+        let initial_temp = self.initial_temperature(&current_state, &mut rng);
+        let mut current_temp = initial_temp;
+        let chains = self.iterations.div_ceil(self.chain_length).max(1);
+        let mut level = 0;
+        let mut accepted = 0;
+        let mut current_score = current_state.fitness().to_f64();
+        let mut best_state = current_state.clone();
+        let mut best = current_score;
+        let mut since_best = 0;
+        let mut restarts = 0;
+        let mut acceptor = Acceptor::new(self.acceptance, current_score);
+        let deluge = matches!(self.acceptance, Acceptance::GreatDeluge { .. });
+        let mut improved = false;
+        let mut stagnant = 0;
 
         for observer in observers.iter_mut() {
             observer.on_start();
             observer.on_iteration(0, &[current_state.clone()]);
+            observer.on_metric(0, "initial_temperature", initial_temp);
+            observer.on_metric(0, "current_fitness", current_score);
+            observer.on_metric(0, "best_fitness", best);
+            observer.on_best(0, &best_state);
+            if deluge {
+                observer.on_metric(0, "water_level", acceptor.level());
+            }
         }
 
         for iteration in 1..=self.iterations {
             let neighbor = current_state.neighbor(&mut rng);
-            let delta = neighbor.fitness().to_f64() - current_state.fitness().to_f64();
+            let neighbor_score = neighbor.fitness().to_f64();
 
-            if delta < 0.0 || rng.gen::<f64>() < (-delta / current_temp).exp() {
+            if acceptor.accept(current_score, neighbor_score, best, current_temp, &mut rng) {
                 current_state = neighbor;
+                current_score = neighbor_score;
+                accepted += 1;
+            }
+
+            if current_score < best {
+                best_state = current_state.clone();
+                best = current_score;
+                improved = true;
+                since_best = 0;
+                for observer in observers.iter_mut() {
+                    observer.on_best(iteration, &best_state);
+                }
+            } else {
+                since_best += 1;
+            }
+
+            let mut restarted = false;
+            if self.restart_after > 0 && since_best >= self.restart_after {
+                since_best = 0;
+                if current_score > best {
+                    current_state = best_state.clone();
+                    current_score = best;
+                    restarts += 1;
+                    restarted = true;
+                }
             }
 
             archive.add(current_state.clone());
 
             for observer in observers.iter_mut() {
                 observer.on_iteration(iteration, &[current_state.clone()]);
+                observer.on_metric(iteration, "temperature", current_temp);
+                observer.on_metric(iteration, "current_fitness", current_score);
+                observer.on_metric(iteration, "best_fitness", best);
+                if deluge {
+                    observer.on_metric(iteration, "water_level", acceptor.level());
+                }
+                if restarted {
+                    observer.on_metric(iteration, "restart_from_best", restarts as f64);
+                }
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
             }
 
-            current_temp *= 1.0 - self.cooling_rate;
+            if iteration % self.chain_length == 0 {
+                level += 1;
+                stagnant = if improved { 0 } else { stagnant + 1 };
+                improved = false;
+                let chain = ChainStatistics {
+                    level,
+                    progress: level as f64 / chains as f64,
+                    acceptance: accepted as f64 / self.chain_length as f64,
+                    stagnant,
+                };
+                let next_temp = self.schedule.next(initial_temp, current_temp, &chain);
+                if next_temp > current_temp {
+                    stagnant = 0;
+                }
+                current_temp = next_temp;
+                accepted = 0;
+            }
         }
 
         for observer in observers.iter_mut() {
@@ -0,0 +1,299 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use crate::gp::{GpConfig, PrimitiveSet, Tree, Node, TreeIndividual};
+use crate::genetic_algorithm::GeneticAlgorithm;
+use crate::selection::Tournament;
+
+
+// Fitness given to expressions that produce NaN or infinite predictions.
+const INVALID_FITNESS: f64 = 1e30;
+
+#[derive(Debug)]
+pub enum DatasetError {
+    Io(std::io::Error),
+    Empty,
+    /// A field on the given 1-based line is not a number.
+    Parse { line: usize, value: String },
+    /// A row on the given 1-based line has the wrong number of fields.
+    RaggedRow { line: usize, expected: usize, found: usize },
+    MissingTarget(String),
+}
+
+impl fmt::Display for DatasetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatasetError::Io(error) => write!(f, "cannot read dataset: {}", error),
+            DatasetError::Empty => write!(f, "dataset has no rows"),
+            DatasetError::Parse { line, value } => write!(f, "line {}: '{}' is not a number", line, value),
+            DatasetError::RaggedRow { line, expected, found } => {
+                write!(f, "line {}: expected {} fields, found {}", line, expected, found)
+            }
+            DatasetError::MissingTarget(name) => write!(f, "no column named '{}'", name),
+        }
+    }
+}
+
+impl std::error::Error for DatasetError {}
+
+impl From<std::io::Error> for DatasetError {
+    fn from(error: std::io::Error) -> Self {
+        DatasetError::Io(error)
+    }
+}
+
+/// Numeric samples with named features and one target column.
+#[derive(Clone, Debug)]
+pub struct Dataset {
+    pub feature_names: Vec<String>,
+    pub features: Vec<Vec<f64>>,
+    pub targets: Vec<f64>,
+}
+
+impl Dataset {
+    /// Reads a comma-separated file. See `from_csv_str`.
+    pub fn load_csv<P>(path: P, target: Option<&str>) -> Result<Self, DatasetError>
+    where
+        P: AsRef<Path>,
+    {
+        Self::from_csv_str(&fs::read_to_string(path)?, target)
+    }
+
+    /// Parses comma-separated numbers. A first row with no numeric field is
+    /// taken as the header, while one mixing numbers and text is a `Parse`
+    /// error; without a header, columns are named
+    /// `x0, x1, ...`. The `target` column (by header name) is predicted from
+    /// the others; by default it is the last column. Blank lines are skipped.
+    pub fn from_csv_str(text: &str, target: Option<&str>) -> Result<Self, DatasetError> {
+        let mut rows = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| (index + 1, line.split(',').map(str::trim).collect::<Vec<&str>>()))
+            .peekable();
+
+        let (first_line, first) = rows.peek().ok_or(DatasetError::Empty)?;
+        let columns = first.len();
+        let numeric = first.iter().filter(|field| field.parse::<f64>().is_ok()).count();
+        let header: Option<Vec<String>> = if numeric == 0 {
+            let (_, names) = rows.next().unwrap();
+            Some(names.iter().map(|name| name.to_string()).collect())
+        } else if numeric < columns {
+            let value = first.iter().find(|field| field.parse::<f64>().is_err()).unwrap();
+            return Err(DatasetError::Parse { line: *first_line, value: value.to_string() });
+        } else {
+            None
+        };
+        let names = header.unwrap_or_else(|| (0..columns).map(|index| format!("x{}", index)).collect());
+
+        let target_index = match target {
+            Some(name) => names
+                .iter()
+                .position(|column| column == name)
+                .ok_or_else(|| DatasetError::MissingTarget(name.to_string()))?,
+            None => columns - 1,
+        };
+
+        let mut features = Vec::new();
+        let mut targets = Vec::new();
+        for (line, fields) in rows {
+            if fields.len() != columns {
+                return Err(DatasetError::RaggedRow { line, expected: columns, found: fields.len() });
+            }
+            let mut values = Vec::with_capacity(columns);
+            for field in fields {
+                let value = field
+                    .parse::<f64>()
+                    .map_err(|_| DatasetError::Parse { line, value: field.to_string() })?;
+                values.push(value);
+            }
+            targets.push(values.remove(target_index));
+            features.push(values);
+        }
+
+        if targets.is_empty() {
+            return Err(DatasetError::Empty);
+        }
+
+        let feature_names = names
+            .into_iter()
+            .enumerate()
+            .filter(|&(index, _)| index != target_index)
+            .map(|(_, name)| name)
+            .collect();
+
+        Ok(Self { feature_names, features, targets })
+    }
+
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+/// Error measure minimised by symbolic regression.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Metric {
+    MeanSquaredError,
+    /// Minimises `1 - R²`.
+    RSquared,
+}
+
+/// Symbolic regression of a dataset's target on its features with tree GP.
+pub struct SymbolicRegression {
+    pub dataset: Arc<Dataset>,
+    pub metric: Metric,
+    pub parsimony: f64,
+    pub max_depth: usize,
+    pub max_size: usize,
+}
+
+impl SymbolicRegression {
+    pub fn new(dataset: Dataset) -> Self {
+        Self {
+            dataset: Arc::new(dataset),
+            metric: Metric::MeanSquaredError,
+            parsimony: 0.0,
+            max_depth: 10,
+            max_size: 100,
+        }
+    }
+
+    pub fn with_metric(mut self, metric: Metric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Adds `parsimony` times the expression size to the fitness.
+    pub fn with_parsimony(mut self, parsimony: f64) -> Self {
+        self.parsimony = parsimony;
+        self
+    }
+
+    pub fn with_limits(mut self, max_depth: usize, max_size: usize) -> Self {
+        self.max_depth = max_depth;
+        self.max_size = max_size;
+        self
+    }
+
+    /// Arithmetic primitives with one variable per feature, named after the
+    /// dataset's columns.
+    pub fn primitives(&self) -> PrimitiveSet {
+        let mut primitives = PrimitiveSet::arithmetic(0);
+        for name in &self.dataset.feature_names {
+            primitives.add_variable(name);
+        }
+        primitives
+    }
+
+    pub fn config(&self) -> Arc<GpConfig> {
+        let dataset = Arc::clone(&self.dataset);
+        let metric = self.metric;
+        let config = GpConfig::new(self.primitives(), move |tree: &Tree, primitives: &PrimitiveSet| {
+            let error = match metric {
+                Metric::MeanSquaredError => mse(tree, primitives, &dataset),
+                Metric::RSquared => 1.0 - r_squared(tree, primitives, &dataset),
+            };
+            if error.is_finite() { error } else { INVALID_FITNESS }
+        });
+        Arc::new(
+            config
+                .with_limits(self.max_depth, self.max_size)
+                .with_parsimony(self.parsimony),
+        )
+    }
+
+    /// A generational GA over ramped half-and-half trees with tournament
+    /// selection of size 4.
+    pub fn genetic_algorithm(&self, population_size: usize, generations: usize) -> GeneticAlgorithm<TreeIndividual> {
+        GeneticAlgorithm::from_initializer(self.config().initializer(), population_size, 0.2, 0.9, generations)
+            .with_selection(Tournament::new(4))
+    }
+
+    pub fn mse(&self, individual: &TreeIndividual) -> f64 {
+        mse(&individual.tree, &individual.config().primitives, &self.dataset)
+    }
+
+    pub fn r_squared(&self, individual: &TreeIndividual) -> f64 {
+        r_squared(&individual.tree, &individual.config().primitives, &self.dataset)
+    }
+
+    /// The individual's expression as an infix formula over the feature names.
+    pub fn formula(&self, individual: &TreeIndividual) -> String {
+        formula(&individual.tree, &individual.config().primitives)
+    }
+}
+
+pub fn predictions(tree: &Tree, primitives: &PrimitiveSet, dataset: &Dataset) -> Vec<f64> {
+    dataset
+        .features
+        .iter()
+        .map(|row| tree.evaluate(primitives, row).as_f64())
+        .collect()
+}
+
+pub fn mse(tree: &Tree, primitives: &PrimitiveSet, dataset: &Dataset) -> f64 {
+    let predictions = predictions(tree, primitives, dataset);
+    predictions
+        .iter()
+        .zip(&dataset.targets)
+        .map(|(prediction, target)| (prediction - target).powi(2))
+        .sum::<f64>()
+        / dataset.len().max(1) as f64
+}
+
+/// Coefficient of determination; 1 for a perfect fit. A constant target
+/// gives 1 for a perfect fit and 0 otherwise.
+pub fn r_squared(tree: &Tree, primitives: &PrimitiveSet, dataset: &Dataset) -> f64 {
+    let count = dataset.len().max(1) as f64;
+    let mean = dataset.targets.iter().sum::<f64>() / count;
+    let total: f64 = dataset.targets.iter().map(|target| (target - mean).powi(2)).sum();
+    let residual = mse(tree, primitives, dataset) * count;
+    if total == 0.0 {
+        return if residual == 0.0 { 1.0 } else { 0.0 };
+    }
+    1.0 - residual / total
+}
+
+/// Writes `tree` as an infix formula, e.g. `((x + 0.5) * y)`. Functions that
+/// are not arithmetic operators are written as calls, `name(a, b)`.
+pub fn formula(tree: &Tree, primitives: &PrimitiveSet) -> String {
+    if tree.is_empty() {
+        return String::new();
+    }
+    write_formula(tree, primitives, &mut 0)
+}
+
+fn write_formula(tree: &Tree, primitives: &PrimitiveSet, position: &mut usize) -> String {
+    let node = tree.nodes[*position];
+    *position += 1;
+    match node {
+        Node::Function(index) => {
+            let function = &primitives.functions[index];
+            let args: Vec<String> = (0..function.arg_types.len())
+                .map(|_| write_formula(tree, primitives, position))
+                .collect();
+            let operator = matches!(function.name.as_str(), "+" | "-" | "*" | "/");
+            if operator && args.len() == 2 {
+                // Only constants start with a sign: `x - -0.5` reads as
+                // `x + 0.5`, and `x * -0.5` as `x * (-0.5)`.
+                match (function.name.as_str(), args[1].strip_prefix('-')) {
+                    ("+", Some(magnitude)) => format!("({} - {})", args[0], magnitude),
+                    ("-", Some(magnitude)) => format!("({} + {})", args[0], magnitude),
+                    (name, Some(_)) => format!("({} {} ({}))", args[0], name, args[1]),
+                    (name, None) => format!("({} {} {})", args[0], name, args[1]),
+                }
+            } else {
+                format!("{}({})", function.name, args.join(", "))
+            }
+        }
+        Node::Terminal(index) => primitives.terminals[index].name.clone(),
+        // Shortest representation that reads back as the same value, so the
+        // formula is exactly the scored model.
+        Node::Constant(value) => value.as_f64().to_string(),
+    }
+}
@@ -0,0 +1,225 @@
+use rand::prelude::*;
+use std::collections::HashMap;
+use crate::optimizer::Optimizer;
+use crate::archive::Archive;
+use crate::individual::{Neighborhood, FitnessValue};
+use crate::observer::Observer;
+use crate::initializer::Initializer;
+
+
+/// When a tabu move may be made anyway.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Aspiration {
+    /// Tabu moves are never made while an admissible move exists.
+    None,
+    /// A tabu move is allowed if it leads to a new best solution.
+    #[default]
+    BestSoFar,
+}
+
+/// Use of the long-term memory of how often each attribute has been moved.
+/// Candidate moves are ranked by their fitness plus `weight` times the
+/// average share of past moves that involved their attributes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum FrequencyMemory {
+    #[default]
+    None,
+    /// Penalises frequently moved attributes, steering the search elsewhere.
+    Diversification { weight: f64 },
+    /// Favours frequently moved attributes, concentrating the search on them.
+    Intensification { weight: f64 },
+    /// Diversifies and intensifies in turn, for `period` iterations each.
+    Alternating { weight: f64, period: usize },
+}
+
+impl FrequencyMemory {
+    // Signed weight of the frequency term at `iteration`.
+    fn weight(&self, iteration: usize) -> f64 {
+        match *self {
+            FrequencyMemory::None => 0.0,
+            FrequencyMemory::Diversification { weight } => weight,
+            FrequencyMemory::Intensification { weight } => -weight,
+            FrequencyMemory::Alternating { weight, period } => {
+                if (iteration / period.max(1)).is_multiple_of(2) { weight } else { -weight }
+            }
+        }
+    }
+}
+
+/// Tabu search over an enumerable `Neighborhood`. Each iteration evaluates
+/// the candidate moves and makes the best admissible one, even if it is
+/// worse than the current solution. The attributes of every move made stay
+/// tabu for `tenure` iterations. If every candidate is tabu, the move whose
+/// tabu status expires first is made.
+///
+/// Observers receive the current solution each iteration and the
+/// `current_fitness`, `best_fitness` and `tabu_size` metrics.
+pub struct TabuSearch<I>
+where
+    I: Neighborhood,
+{
+    pub iterations: usize,
+    pub tenure: usize,
+    /// Moves sampled per iteration; 0 evaluates the whole neighbourhood.
+    pub candidates: usize,
+    pub aspiration: Aspiration,
+    pub memory: FrequencyMemory,
+    pub initializer: Initializer<I>,
+}
+
+impl<I> TabuSearch<I>
+where
+    I: Neighborhood + Default,
+{
+    pub fn new(iterations: usize, tenure: usize) -> Self {
+        Self::from_initializer(Initializer::default(), iterations, tenure)
+    }
+}
+
+impl<I> TabuSearch<I>
+where
+    I: Neighborhood,
+{
+    /// Starts from the first seed of `initializer`, or from a generated
+    /// solution if it has no seeds.
+    pub fn from_initializer(initializer: Initializer<I>, iterations: usize, tenure: usize) -> Self {
+        Self {
+            iterations,
+            tenure,
+            candidates: 0,
+            aspiration: Aspiration::BestSoFar,
+            memory: FrequencyMemory::None,
+            initializer,
+        }
+    }
+
+    pub fn with_candidates(mut self, candidates: usize) -> Self {
+        self.candidates = candidates;
+        self
+    }
+
+    pub fn with_aspiration(mut self, aspiration: Aspiration) -> Self {
+        self.aspiration = aspiration;
+        self
+    }
+
+    pub fn with_memory(mut self, memory: FrequencyMemory) -> Self {
+        self.memory = memory;
+        self
+    }
+
+    pub fn with_start(mut self, start: I) -> Self {
+        self.initializer = self.initializer.with_seeds(vec![start]);
+        self
+    }
+}
+
+struct Candidate<I>
+where
+    I: Neighborhood,
+{
+    solution: I,
+    score: f64,
+    rank: f64,
+    attributes: Vec<I::Attribute>,
+}
+
+impl<I> Optimizer<I> for TabuSearch<I>
+where
+    I: Neighborhood,
+    I::Fitness: PartialOrd + FitnessValue,
+{
+    fn optimize<A, O>(&self, archive: &mut A, observers: &mut [O])
+    where
+        A: Archive<Solution = I, Fitness = I::Fitness>,
+        O: Observer<I>,
+    {
+        let mut rng = thread_rng();
+        let mut current = self.initializer.individual(&mut rng);
+        let mut current_score = current.fitness().to_f64();
+        let mut best = current_score;
+        // Iteration until which each attribute stays tabu, and how often it
+        // has been moved.
+        let mut tabu: HashMap<I::Attribute, usize> = HashMap::new();
+        let mut frequency: HashMap<I::Attribute, usize> = HashMap::new();
+
+        archive.add(current.clone());
+        for observer in observers.iter_mut() {
+            observer.on_start();
+            observer.on_iteration(0, std::slice::from_ref(&current));
+        }
+
+        for iteration in 1..=self.iterations {
+            let mut moves = current.moves();
+            if self.candidates > 0 && self.candidates < moves.len() {
+                moves.shuffle(&mut rng);
+                moves.truncate(self.candidates);
+            }
+            if moves.is_empty() {
+                break;
+            }
+
+            let weight = self.memory.weight(iteration);
+            let mut admissible: Option<Candidate<I>> = None;
+            let mut fallback: Option<(usize, Candidate<I>)> = None;
+
+            for step in &moves {
+                let attributes = current.attributes(step);
+                let solution = current.apply(step);
+                let score = solution.fitness().to_f64();
+                let share = attributes
+                    .iter()
+                    .map(|attribute| frequency.get(attribute).copied().unwrap_or(0))
+                    .sum::<usize>() as f64
+                    / (attributes.len().max(1) * iteration) as f64;
+                let candidate = Candidate { solution, score, rank: score + weight * share, attributes };
+
+                let expiry = candidate
+                    .attributes
+                    .iter()
+                    .filter_map(|attribute| tabu.get(attribute).copied())
+                    .filter(|&until| until >= iteration)
+                    .max();
+                let aspirated = self.aspiration == Aspiration::BestSoFar && score < best;
+
+                match expiry {
+                    Some(until) if !aspirated => {
+                        if fallback.as_ref().is_none_or(|(soonest, _)| until < *soonest) {
+                            fallback = Some((until, candidate));
+                        }
+                    }
+                    _ => {
+                        if admissible.as_ref().is_none_or(|chosen| candidate.rank < chosen.rank) {
+                            admissible = Some(candidate);
+                        }
+                    }
+                }
+            }
+
+            let chosen = admissible.or(fallback.map(|(_, candidate)| candidate)).unwrap();
+            for attribute in &chosen.attributes {
+                tabu.insert(attribute.clone(), iteration + self.tenure);
+                *frequency.entry(attribute.clone()).or_insert(0) += 1;
+            }
+            current = chosen.solution;
+            current_score = chosen.score;
+            best = best.min(current_score);
+            tabu.retain(|_, until| *until > iteration);
+
+            archive.add(current.clone());
+            for observer in observers.iter_mut() {
+                observer.on_iteration(iteration, std::slice::from_ref(&current));
+                observer.on_metric(iteration, "current_fitness", current_score);
+                observer.on_metric(iteration, "best_fitness", best);
+                observer.on_metric(iteration, "tabu_size", tabu.len() as f64);
+            }
+            if observers.iter().any(|observer| observer.should_stop()) {
+                break;
+            }
+        }
+
+        for observer in observers.iter_mut() {
+            observer.on_finish();
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use rand::prelude::*;
+use metaheuristics::acceptance::{Acceptance, Acceptor};
+use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::initializer::Initializer;
+use metaheuristics::Individual;
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::metric_observers::MetricObserver;
+
+#[test]
+fn test_deterministic_rules() {
+    let mut rng = thread_rng();
+
+    let mut threshold = Acceptor::new(Acceptance::ThresholdAccepting, 10.0);
+    assert!(threshold.accept(10.0, 10.9, 10.0, 1.0, &mut rng));
+    assert!(!threshold.accept(10.0, 11.5, 10.0, 1.0, &mut rng));
+
+    let mut deluge = Acceptor::new(Acceptance::GreatDeluge { rain: 1.0 }, 10.0);
+    assert!(deluge.accept(5.0, 10.0, 5.0, 0.0, &mut rng));
+    assert!(!deluge.accept(5.0, 9.5, 5.0, 0.0, &mut rng));
+    assert_eq!(deluge.level(), 8.0);
+
+    let mut record = Acceptor::new(Acceptance::RecordToRecord { deviation: 2.0 }, 10.0);
+    assert!(record.accept(5.0, 6.5, 5.0, 0.0, &mut rng));
+    assert!(!record.accept(6.5, 7.5, 5.0, 0.0, &mut rng));
+
+    let mut climber = Acceptor::new(Acceptance::HillClimbing, 10.0);
+    assert!(climber.accept(5.0, 5.0, 5.0, 100.0, &mut rng));
+    assert!(!climber.accept(5.0, 5.1, 5.0, 100.0, &mut rng));
+}
+
+#[test]
+fn test_late_acceptance_compares_with_old_scores() {
+    let mut rng = thread_rng();
+    let mut late = Acceptor::new(Acceptance::LateAcceptance { length: 2 }, 10.0);
+
+    // Both history slots hold the initial score of 10.
+    assert!(late.accept(3.0, 9.0, 3.0, 0.0, &mut rng));
+    assert!(!late.accept(9.0, 11.0, 3.0, 0.0, &mut rng));
+    // Slot 0 now holds 9 and slot 1 holds 9 as well.
+    assert!(late.accept(9.0, 9.0, 3.0, 0.0, &mut rng));
+    assert!(!late.accept(9.0, 9.5, 3.0, 0.0, &mut rng));
+}
+
+#[test]
+fn test_every_criterion_improves_on_the_start() {
+    let criteria = [
+        Acceptance::Metropolis,
+        Acceptance::ThresholdAccepting,
+        Acceptance::GreatDeluge { rain: 0.02 },
+        Acceptance::RecordToRecord { deviation: 0.5 },
+        Acceptance::LateAcceptance { length: 20 },
+        Acceptance::HillClimbing,
+    ];
+    for acceptance in criteria {
+        let start = NumericIndividual { genes: vec![4.0; 3] };
+        let sa = SimulatedAnnealing::from_initializer(Initializer::from_population(vec![start.clone()]), 1.0, 0.005, 2000)
+            .with_acceptance(acceptance);
+        let mut archive = BasicArchive::new(1);
+        let mut observers = vec![MetricObserver::new()];
+
+        sa.optimize(&mut archive, &mut observers);
+
+        assert!(archive.get_best().unwrap().fitness() < 0.5 * start.fitness(), "{:?}", acceptance);
+    }
+}
+
+#[test]
+fn test_great_deluge_reports_water_level() {
+    let start = NumericIndividual { genes: vec![4.0; 3] };
+    let sa = SimulatedAnnealing::from_initializer(Initializer::from_population(vec![start.clone()]), 1.0, 0.005, 50)
+        .with_acceptance(Acceptance::GreatDeluge { rain: 0.5 });
+    let mut archive = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    let levels = observers[0].values("water_level");
+    assert_eq!(levels.len(), 51);
+    assert_eq!(levels[0], start.fitness());
+    assert!(levels.windows(2).all(|pair| (pair[0] - pair[1] - 0.5).abs() < 1e-9));
+}
@@ -1,11 +1,11 @@
 use metaheuristics::ant_colony::AntColony;
 use metaheuristics::Optimizer;
-use metaheuristics::observer::Observer;
 use metaheuristics::archive::{BasicArchive, Archive};
 use metaheuristics::Individual;
 use metaheuristics::individuals::TourIndividual;
 use metaheuristics::logging_observers::LoggingObserver;
-use metaheuristics::distribution_observers::DistributionObserver;
+use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::initializer::Initializer;
 
 #[test]
 fn test_ant_colony_optimization() {
@@ -32,3 +32,25 @@ fn test_ant_colony_optimization() {
         assert!(fitness >= 0.0);
     }
 }
+
+#[test]
+fn test_ant_colony_tours_can_be_refined() {
+    let distances: Vec<Vec<f64>> = (0..8)
+        .map(|i: i32| (0..8).map(|j: i32| ((i - j).abs() * 3 % 7 + 1) as f64).collect())
+        .collect();
+    let aco = AntColony::new(5, 5, 1.0, 2.0, 0.5, distances);
+    let mut archive: BasicArchive<TourIndividual> = BasicArchive::new(1);
+    aco.optimize(&mut archive, &mut Vec::<LoggingObserver<TourIndividual>>::new());
+
+    let start = archive.get_best().unwrap().clone();
+    let mut cities = start.tour.clone();
+    cities.sort();
+    assert_eq!(cities, (0..8).collect::<Vec<usize>>());
+    let sa = SimulatedAnnealing::from_initializer(Initializer::from_population(vec![start.clone()]), 1.0, 0.01, 200);
+    let mut refined: BasicArchive<TourIndividual> = BasicArchive::new(1);
+    sa.optimize(&mut refined, &mut Vec::<LoggingObserver<TourIndividual>>::new());
+
+    let best = refined.get_best().unwrap();
+    assert!(best.fitness() <= start.fitness());
+    assert_eq!(best.length, TourIndividual::from_permutation(best.tour.clone(), start.distances().clone()).length);
+}
@@ -0,0 +1,106 @@
+use metaheuristics::cma_es::{CmaEs, Restarts, symmetric_eigen};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individual::{Individual, RealVector};
+use metaheuristics::metric_observers::MetricObserver;
+
+// Ellipsoid with condition number 1e6.
+#[derive(Clone, Debug, Default)]
+struct Ellipsoid {
+    genes: Vec<f64>,
+}
+
+impl Individual for Ellipsoid {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        let n = self.genes.len().max(2) as f64;
+        self.genes
+            .iter()
+            .enumerate()
+            .map(|(i, x)| 1e6f64.powf(i as f64 / (n - 1.0)) * x * x)
+            .sum()
+    }
+}
+
+impl RealVector for Ellipsoid {
+    fn genes(&self) -> &[f64] {
+        &self.genes
+    }
+
+    fn genes_mut(&mut self) -> &mut Vec<f64> {
+        &mut self.genes
+    }
+}
+
+// Rastrigin: many local optima around the global one at the origin.
+#[derive(Clone, Debug, Default)]
+struct Rastrigin {
+    genes: Vec<f64>,
+}
+
+impl Individual for Rastrigin {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        let tau = 2.0 * std::f64::consts::PI;
+        10.0 * self.genes.len() as f64 + self.genes.iter().map(|x| x * x - 10.0 * (tau * x).cos()).sum::<f64>()
+    }
+}
+
+impl RealVector for Rastrigin {
+    fn genes(&self) -> &[f64] {
+        &self.genes
+    }
+
+    fn genes_mut(&mut self) -> &mut Vec<f64> {
+        &mut self.genes
+    }
+}
+
+#[test]
+fn test_symmetric_eigen_reconstructs_matrix() {
+    let matrix = vec![vec![4.0, 1.0, 0.5], vec![1.0, 3.0, 0.2], vec![0.5, 0.2, 1.0]];
+    let (values, vectors) = symmetric_eigen(&matrix);
+
+    for i in 0..3 {
+        for j in 0..3 {
+            let rebuilt: f64 = (0..3).map(|k| vectors[i][k] * values[k] * vectors[j][k]).sum();
+            assert!((rebuilt - matrix[i][j]).abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_cma_es_solves_ill_conditioned_ellipsoid() {
+    let cma = CmaEs::new(20_000, vec![(-5.0, 5.0); 6]);
+    let mut archive: BasicArchive<Ellipsoid> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    cma.optimize(&mut archive, &mut observers);
+
+    let best = archive.get_best().unwrap();
+    assert!(best.fitness() < 1e-8, "reached {}", best.fitness());
+    let sigma = observers[0].values("sigma");
+    assert!(!sigma.is_empty());
+    assert!(sigma.last().unwrap() < &sigma[0]);
+    assert_eq!(observers[0].values("mean_5").len(), sigma.len());
+}
+
+#[test]
+fn test_restarts_increase_population_within_budget() {
+    for restarts in [Restarts::Ipop { max_restarts: 9 }, Restarts::Bipop { max_restarts: 9 }] {
+        let cma = CmaEs::new(60_000, vec![(-5.12, 5.12); 4])
+            .with_sigma(2.0)
+            .with_restarts(restarts);
+        let mut archive: BasicArchive<Rastrigin> = BasicArchive::new(5);
+        let mut observers = vec![MetricObserver::new()];
+
+        cma.optimize(&mut archive, &mut observers);
+
+        let best = archive.get_best().unwrap();
+        assert!(best.genes.iter().all(|x| (-5.12..=5.12).contains(x)));
+        assert!(best.fitness() < 5.0, "{:?} reached {}", restarts, best.fitness());
+        assert!(observers[0].values("restart").len() >= 2);
+    }
+}
@@ -0,0 +1,128 @@
+use metaheuristics::cooling::{CoolingSchedule, ChainStatistics, calibrate_temperature, calibrate_threshold};
+use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::Individual;
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::metric_observers::MetricObserver;
+use metaheuristics::initializer::Initializer;
+use metaheuristics::acceptance::Acceptance;
+
+fn chain(level: usize, acceptance: f64, stagnant: usize) -> ChainStatistics {
+    ChainStatistics { level, progress: level as f64 / 10.0, acceptance, stagnant }
+}
+
+#[test]
+fn test_schedules_follow_their_formulas() {
+    let close = |a: f64, b: f64| (a - b).abs() < 1e-12;
+
+    assert!(close(CoolingSchedule::Geometric { rate: 0.1 }.next(10.0, 5.0, &chain(1, 0.5, 0)), 4.5));
+    assert!(close(CoolingSchedule::Linear { end: 0.0 }.next(10.0, 9.0, &chain(5, 0.5, 0)), 5.0));
+    assert!(close(CoolingSchedule::Logarithmic.next(10.0, 10.0, &chain(2, 0.5, 0)), 10.0 * 2f64.ln() / 4f64.ln()));
+    assert!(close(CoolingSchedule::LundyMees { beta: 0.5 }.next(10.0, 2.0, &chain(1, 0.5, 0)), 1.0));
+
+    let adaptive = CoolingSchedule::Adaptive { initial_ratio: 0.8, final_ratio: 0.0, factor: 0.5 };
+    assert!(close(adaptive.next(10.0, 4.0, &chain(5, 0.9, 0)), 2.0));
+    assert!(close(adaptive.next(10.0, 4.0, &chain(5, 0.1, 0)), 8.0));
+
+    let reheating = CoolingSchedule::Reheating { rate: 0.5, patience: 3, reheat: 0.5 };
+    assert!(close(reheating.next(10.0, 1.0, &chain(4, 0.5, 2)), 0.5));
+    assert!(close(reheating.next(10.0, 1.0, &chain(4, 0.5, 3)), 5.0));
+}
+
+#[test]
+fn test_temperature_changes_once_per_chain() {
+    let sa: SimulatedAnnealing<NumericIndividual> = SimulatedAnnealing::new(8.0, 0.5, 30).with_chain_length(10);
+    let mut archive = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    let temperatures = observers[0].values("temperature");
+    let expected: Vec<f64> = [8.0, 4.0, 2.0].iter().flat_map(|&t| vec![t; 10]).collect();
+    assert_eq!(temperatures, expected);
+}
+
+#[test]
+fn test_every_schedule_improves_on_the_start() {
+    let schedules = [
+        CoolingSchedule::Geometric { rate: 0.01 },
+        CoolingSchedule::Linear { end: 0.0 },
+        CoolingSchedule::Logarithmic,
+        CoolingSchedule::LundyMees { beta: 0.01 },
+        CoolingSchedule::Adaptive { initial_ratio: 0.5, final_ratio: 0.01, factor: 0.9 },
+        CoolingSchedule::Reheating { rate: 0.05, patience: 20, reheat: 0.5 },
+    ];
+    for schedule in schedules {
+        let start = NumericIndividual { genes: vec![4.0; 3] };
+        let sa = SimulatedAnnealing::from_initializer(Initializer::from_population(vec![start.clone()]), 1.0, 0.0, 2000)
+            .with_schedule(schedule)
+            .with_chain_length(5);
+        let mut archive = BasicArchive::new(1);
+        let mut observers = vec![MetricObserver::new()];
+
+        sa.optimize(&mut archive, &mut observers);
+
+        assert!(archive.get_best().unwrap().fitness() < start.fitness(), "{:?}", schedule);
+    }
+}
+
+#[test]
+fn test_calibrated_temperature_hits_target_acceptance() {
+    let single = calibrate_temperature(&[2.0, -1.0, 0.0], 0.5).unwrap();
+    assert!((single - 2.0 / 2f64.ln()).abs() < 1e-9);
+
+    let deltas = [0.5, 1.0, 4.0, 10.0];
+    let temperature = calibrate_temperature(&deltas, 0.8).unwrap();
+    let acceptance = deltas.iter().map(|d| (-d / temperature).exp()).sum::<f64>() / deltas.len() as f64;
+    assert!((acceptance - 0.8).abs() < 1e-9);
+
+    assert_eq!(calibrate_temperature(&[-1.0, 0.0], 0.8), None);
+}
+
+#[test]
+fn test_annealer_reports_calibrated_initial_temperature() {
+    let sa: SimulatedAnnealing<NumericIndividual> =
+        SimulatedAnnealing::new(1e-3, 0.01, 50).with_initial_acceptance(0.8, 200);
+    let mut archive = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    let chosen = observers[0].values("initial_temperature");
+    assert_eq!(chosen.len(), 1);
+    assert!(chosen[0] > 1e-3);
+    assert_eq!(observers[0].values("temperature")[0], chosen[0]);
+}
+
+#[test]
+fn test_calibrated_threshold_passes_target_share() {
+    let deltas = [4.0, -1.0, 1.0, 10.0, 0.5, 2.0];
+    let threshold = calibrate_threshold(&deltas, 0.5).unwrap();
+    let passed = deltas.iter().filter(|&&d| d > 0.0 && d < threshold).count();
+    assert_eq!(passed, 3);
+    assert!(threshold > 2.0 && threshold < 4.0);
+
+    assert!(calibrate_threshold(&deltas, 0.99).unwrap() > 10.0);
+    assert_eq!(calibrate_threshold(&[-1.0, 0.0], 0.8), None);
+}
+
+#[test]
+fn test_calibration_follows_acceptance_rule() {
+    let start = NumericIndividual { genes: vec![1.0; 5] };
+    let calibrated = |acceptance: Acceptance| {
+        let sa = SimulatedAnnealing::from_initializer(Initializer::from_population(vec![start.clone()]), 1e-3, 0.01, 1)
+            .with_acceptance(acceptance)
+            .with_initial_acceptance(0.8, 200);
+        let mut archive = BasicArchive::new(1);
+        let mut observers = vec![MetricObserver::new()];
+        sa.optimize(&mut archive, &mut observers);
+        observers[0].values("initial_temperature")[0]
+    };
+
+    let metropolis = calibrated(Acceptance::Metropolis);
+    let threshold = calibrated(Acceptance::ThresholdAccepting);
+    assert!(metropolis > 1e-3 && threshold > 1e-3);
+    assert_eq!(calibrated(Acceptance::HillClimbing), 1e-3);
+    assert_eq!(calibrated(Acceptance::GreatDeluge { rain: 0.1 }), 1e-3);
+}
@@ -1,10 +1,8 @@
 use metaheuristics::genetic_algorithm::GeneticAlgorithm;
 use metaheuristics::optimizer::Optimizer;
-use metaheuristics::observer::Observer;
 use metaheuristics::archive::{BasicArchive, Archive};
-use metaheuristics::individual::{Individual, Crossover, Mutate, FitnessValue};
+use metaheuristics::individual::{Individual, Crossover, Mutate};
 use metaheuristics::logging_observers::LoggingObserver;
-use metaheuristics::distribution_observers::DistributionObserver;
 use rand::prelude::*;
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,43 @@
+use metaheuristics::differential_evolution::{DifferentialEvolution, Strategy, Variant};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::metric_observers::MetricObserver;
+
+#[test]
+fn test_classic_strategies_minimise_sphere() {
+    let strategies = [Strategy::Rand1Bin, Strategy::Best1Bin, Strategy::CurrentToBest1Bin, Strategy::Rand2Exp];
+
+    for strategy in strategies {
+        // The greedy best-based strategies stagnate now and then with 30
+        // members; 50 keeps every strategy orders of magnitude below 1e-2.
+        let de = DifferentialEvolution::new(50, 150, vec![(-5.0, 5.0); 5]).with_strategy(strategy);
+        let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+        let mut observers = vec![MetricObserver::new()];
+
+        de.optimize(&mut archive, &mut observers);
+
+        let best = archive.get_best().unwrap();
+        assert!(best.genes.iter().all(|x| (-5.0..=5.0).contains(x)));
+        assert!(best.genes.iter().map(|x| x * x).sum::<f64>() < 1e-2, "{:?} reached {:?}", strategy, best.genes);
+        assert_eq!(observers[0].values("scale"), vec![0.5; 150]);
+    }
+}
+
+#[test]
+fn test_adaptive_variants_minimise_sphere() {
+    for variant in [Variant::jade(), Variant::shade()] {
+        let de = DifferentialEvolution::new(30, 150, vec![(-5.0, 5.0); 5]).with_variant(variant);
+        let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+        let mut observers = vec![MetricObserver::new()];
+
+        de.optimize(&mut archive, &mut observers);
+
+        let best = archive.get_best().unwrap();
+        assert!(best.genes.iter().map(|x| x * x).sum::<f64>() < 1e-4, "{:?} reached {:?}", variant, best.genes);
+        let rates = observers[0].values("crossover_rate");
+        assert_eq!(rates.len(), 150);
+        assert!(rates.iter().all(|&cr| (0.0..=1.0).contains(&cr)));
+        assert!(observers[0].values("scale").iter().all(|&f| f > 0.0 && f <= 1.0));
+    }
+}
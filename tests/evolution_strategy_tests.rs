@@ -0,0 +1,87 @@
+use metaheuristics::evolution_strategy::{OnePlusOneEs, EvolutionStrategy, SurvivorSelection, StepSizes};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individual::{Individual, RealVector};
+use metaheuristics::metric_observers::MetricObserver;
+
+#[derive(Clone, Debug, Default)]
+struct Sphere {
+    genes: Vec<f64>,
+}
+
+impl Individual for Sphere {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        self.genes.iter().map(|x| x * x).sum()
+    }
+}
+
+impl RealVector for Sphere {
+    fn genes(&self) -> &[f64] {
+        &self.genes
+    }
+
+    fn genes_mut(&mut self) -> &mut Vec<f64> {
+        &mut self.genes
+    }
+}
+
+#[test]
+fn test_one_plus_one_es_shrinks_step_size_on_sphere() {
+    let es = OnePlusOneEs::new(1.0, 3000, vec![(-5.0, 5.0); 5]);
+    let mut archive: BasicArchive<Sphere> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    es.optimize(&mut archive, &mut observers);
+
+    let best = archive.get_best().unwrap();
+    assert!(best.fitness() < 1e-6, "reached {}", best.fitness());
+    let sigma = observers[0].values("sigma");
+    assert_eq!(sigma.len(), 3000);
+    assert!(sigma.last().unwrap() < &0.01);
+}
+
+#[test]
+fn test_self_adaptive_strategies_converge_on_sphere() {
+    for selection in [SurvivorSelection::Plus, SurvivorSelection::Comma] {
+        for step_sizes in [StepSizes::Isotropic, StepSizes::PerCoordinate] {
+            let es = EvolutionStrategy::new(5, 35, 300, vec![(-5.0, 5.0); 5])
+                .with_selection(selection)
+                .with_step_sizes(step_sizes, 1.0);
+            let mut archive: BasicArchive<Sphere> = BasicArchive::new(5);
+            let mut observers = vec![MetricObserver::new()];
+
+            es.optimize(&mut archive, &mut observers);
+
+            let best = archive.get_best().unwrap();
+            assert!(best.genes.iter().all(|x| (-5.0..=5.0).contains(x)));
+            assert!(best.fitness() < 1e-4, "{:?}/{:?} reached {}", selection, step_sizes, best.fitness());
+            let sigma = observers[0].values("sigma");
+            assert_eq!(sigma.len(), 300);
+            assert!(sigma.last().unwrap() < &sigma[0]);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "at least mu offspring")]
+fn test_comma_selection_needs_enough_offspring() {
+    let es = EvolutionStrategy::new(10, 5, 10, vec![(-1.0, 1.0); 2]);
+    let mut archive: BasicArchive<Sphere> = BasicArchive::new(1);
+    es.optimize(&mut archive, &mut Vec::<MetricObserver<Sphere>>::new());
+}
+
+#[test]
+fn test_zero_parents_and_zero_window_are_rejected() {
+    let bounds = vec![(-1.0, 1.0); 2];
+    assert!(std::panic::catch_unwind(|| EvolutionStrategy::<Sphere>::new(0, 5, 10, bounds.clone())).is_err());
+    assert!(std::panic::catch_unwind(|| OnePlusOneEs::<Sphere>::new(0.5, 10, bounds.clone()).with_success_rule(0, 0.85)).is_err());
+
+    let mut es = OnePlusOneEs::<Sphere>::new(0.5, 10, bounds);
+    es.window = 0;
+    let run = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        es.optimize(&mut BasicArchive::new(1), &mut Vec::<MetricObserver<Sphere>>::new());
+    }));
+    assert!(run.is_err());
+}
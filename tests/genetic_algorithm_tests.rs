@@ -1,12 +1,9 @@
-use rand::prelude::*;
 use metaheuristics::genetic_algorithm::GeneticAlgorithm;
 use metaheuristics::optimizer::Optimizer;
-use metaheuristics::observer::Observer;
 use metaheuristics::archive::{BasicArchive, Archive};
 use metaheuristics::Individual;
 use metaheuristics::individuals::NumericIndividual;
 use metaheuristics::logging_observers::LoggingObserver;
-use metaheuristics::distribution_observers::DistributionObserver;
 
 #[test]
 fn test_genetic_algorithm_numeric_individual() {
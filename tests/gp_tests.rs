@@ -0,0 +1,132 @@
+use rand::prelude::*;
+use std::sync::Arc;
+use metaheuristics::gp::{GpConfig, PrimitiveSet, Tree, Node, Type, Value, TreeIndividual, TreeMutation};
+use metaheuristics::individual::{Crossover, Mutate};
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::selection::Tournament;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::logging_observers::LoggingObserver;
+
+// Float programs that may branch on a comparison, so crossover and mutation
+// must respect the Bool/Float types.
+fn typed_primitives() -> PrimitiveSet {
+    let mut primitives = PrimitiveSet::arithmetic(1);
+    primitives
+        .add_function("<", vec![Type::Float, Type::Float], Type::Bool, |args| {
+            Value::Bool(args[0].as_f64() < args[1].as_f64())
+        })
+        .add_function("if", vec![Type::Bool, Type::Float, Type::Float], Type::Float, |args| {
+            if args[0].as_bool() { args[1] } else { args[2] }
+        })
+        .add_constant("true", Value::Bool(true));
+    primitives
+}
+
+fn squared_error(tree: &Tree, primitives: &PrimitiveSet) -> f64 {
+    (-10..=10)
+        .map(|i| {
+            let x = i as f64 / 5.0;
+            (tree.evaluate(primitives, &[x]).as_f64() - (x * x + x)).powi(2)
+        })
+        .sum()
+}
+
+#[test]
+fn test_ramped_half_and_half_respects_depths_and_types() {
+    let mut rng = thread_rng();
+    let config = Arc::new(GpConfig::new(typed_primitives(), squared_error).with_init_depth(2, 4));
+    let population = config.ramped_half_and_half(30, &mut rng);
+
+    assert_eq!(population.len(), 30);
+    for (index, individual) in population.iter().enumerate() {
+        assert!(individual.depth() <= 4);
+        assert!(matches!(individual.evaluate(&[0.5]), Value::Float(_)));
+        if index % 2 == 0 {
+            assert!(individual.depth() >= 2);
+        }
+    }
+}
+
+#[test]
+fn test_variation_keeps_trees_typed_and_limited() {
+    let mut rng = thread_rng();
+    let config = Arc::new(GpConfig::new(typed_primitives(), squared_error).with_limits(6, 40));
+    let mut population: Vec<TreeIndividual> = config.ramped_half_and_half(20, &mut rng);
+
+    for _ in 0..500 {
+        let a = rng.gen_range(0..population.len());
+        let b = rng.gen_range(0..population.len());
+        let mut child = population[a].crossover(&population[b], &mut rng);
+        child.mutate(&mut rng);
+        assert!(matches!(child.evaluate(&[1.5]), Value::Float(_)));
+        if config.within_limits(&population[a].tree) {
+            assert!(child.depth() <= 6 && child.size() <= 40);
+        }
+        population[a] = child;
+    }
+
+    let tree = population.iter().max_by_key(|ind| ind.size()).unwrap();
+    let hoisted = config.mutate(&tree.tree, TreeMutation::Hoist, &mut rng);
+    assert!(hoisted.len() <= tree.size());
+}
+
+#[test]
+fn test_genetic_programming_fits_polynomial() {
+    let config = Arc::new(
+        GpConfig::new(PrimitiveSet::arithmetic(1), squared_error)
+            .with_limits(8, 60)
+            .with_parsimony(1e-4),
+    );
+    let ga = GeneticAlgorithm::from_initializer(config.initializer(), 200, 0.2, 0.9, 30)
+        .with_selection(Tournament::new(4));
+    let mut archive: BasicArchive<TreeIndividual> = BasicArchive::new(5);
+    let mut observers = vec![LoggingObserver::new(false, false, false)];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    let best = archive.get_best().unwrap();
+    assert!(best.raw_fitness < 1.0, "best program {} has error {}", best, best.raw_fitness);
+    assert!(!best.to_string().is_empty());
+}
+
+#[test]
+fn test_hand_built_tree_with_ephemeral_terminal_evaluates() {
+    let primitives = PrimitiveSet::arithmetic(1);
+    let ephemeral = primitives.terminals.iter().position(|terminal| terminal.name == "const").unwrap();
+    let tree = Tree::new(vec![Node::Terminal(ephemeral)]);
+
+    let value = tree.evaluate(&primitives, &[0.0]).as_f64();
+    assert!((-1.0..1.0).contains(&value));
+}
+
+#[test]
+#[should_panic(expected = "no finite tree returns Bool")]
+fn test_primitive_sets_without_finite_trees_are_rejected() {
+    // Bool is only produced by `not`, which needs a Bool itself.
+    let mut endless = PrimitiveSet::arithmetic(1);
+    endless
+        .add_function("not", vec![Type::Bool], Type::Bool, |args| Value::Bool(!args[0].as_bool()))
+        .add_function("if", vec![Type::Bool, Type::Float, Type::Float], Type::Float, |args| {
+            if args[0].as_bool() { args[1] } else { args[2] }
+        });
+    assert_eq!(endless.min_depth(Type::Bool), None);
+    GpConfig::new(endless, squared_error);
+}
+
+#[test]
+fn test_trees_close_through_nullary_functions() {
+    // Without terminals, `full` closes the tree through the nullary `one`
+    // once the depth runs out.
+    let mut nullary = PrimitiveSet::new(Type::Float);
+    nullary
+        .add_float_function("neg", 1, |args| -args[0])
+        .add_float_function("+", 2, |args| args[0] + args[1])
+        .add_float_function("one", 0, |_| 1.0);
+    assert_eq!(nullary.min_depth(Type::Float), Some(1));
+    let config = GpConfig::new(nullary, squared_error);
+    for _ in 0..20 {
+        let tree = config.full(Type::Float, 3, &mut thread_rng());
+        assert!(tree.depth(&config.primitives) <= 3);
+    }
+}
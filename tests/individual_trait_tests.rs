@@ -1,11 +1,9 @@
 use metaheuristics::individual::{Individual, Crossover, Mutate};
 use metaheuristics::genetic_algorithm::GeneticAlgorithm;
 use metaheuristics::optimizer::Optimizer;
-use metaheuristics::observer::Observer;
 use metaheuristics::archive::{BasicArchive, Archive};
 use rand::prelude::*;
 use metaheuristics::logging_observers::LoggingObserver;
-use metaheuristics::distribution_observers::DistributionObserver;
 
 
 #[derive(Clone, Debug)]
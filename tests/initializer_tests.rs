@@ -0,0 +1,70 @@
+use rand::prelude::*;
+use metaheuristics::initializer::Initializer;
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::Individual;
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::distribution_observers::DistributionObserver;
+
+fn random_individual(rng: &mut ThreadRng) -> NumericIndividual {
+    NumericIndividual { genes: (0..10).map(|_| rng.gen_range(-1.0..1.0)).collect() }
+}
+
+#[test]
+fn test_initializer_population_mixes_seeds_and_generator() {
+    let mut rng = thread_rng();
+    let seed = NumericIndividual { genes: vec![0.0; 10] };
+
+    let population = Initializer::mixed(vec![seed.clone()], random_individual).population(4, &mut rng);
+    assert_eq!(population.len(), 4);
+    assert_eq!(population[0].genes, seed.genes);
+    assert!(population.iter().all(|ind| ind.genes.len() == 10));
+
+    let population = Initializer::from_population(vec![seed.clone()]).population(3, &mut rng);
+    assert!(population.iter().all(|ind| ind.genes == seed.genes));
+
+    let front = NumericIndividual { genes: vec![1.0; 10] };
+    let initializer = Initializer::from_population(vec![seed.clone()]).with_seeds(vec![front.clone()]);
+    let genes: Vec<_> = initializer.seeds().iter().map(|ind| ind.genes.clone()).collect();
+    assert_eq!(genes, vec![front.genes, seed.genes]);
+}
+
+#[test]
+#[should_panic(expected = "at least one individual")]
+fn test_empty_seed_population_is_rejected() {
+    Initializer::<NumericIndividual>::from_population(vec![]);
+}
+
+#[test]
+fn test_genetic_algorithm_warm_start_from_seed() {
+    let seed = NumericIndividual { genes: vec![0.0; 10] };
+    let ga = GeneticAlgorithm::from_initializer(
+        Initializer::mixed(vec![seed], random_individual),
+        20,
+        0.1,
+        0.8,
+        5,
+    );
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![DistributionObserver::new()];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    assert_eq!(archive.get_best().unwrap().fitness(), 0.0);
+    assert!(observers[0].distributions[0].iter().all(|ind| ind.genes.len() == 10));
+}
+
+#[test]
+fn test_simulated_annealing_starts_from_seed() {
+    let seed = NumericIndividual { genes: vec![3.0, 4.0] };
+    let sa = SimulatedAnnealing::from_initializer(Initializer::from_population(vec![seed]), 1.0, 0.01, 50);
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![DistributionObserver::new()];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    assert_eq!(observers[0].distributions[0][0].genes, vec![3.0, 4.0]);
+    assert!(archive.get_best().unwrap().genes.len() == 2);
+}
@@ -0,0 +1,160 @@
+use rand::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use metaheuristics::genetic_algorithm::{GeneticAlgorithm, Replacement};
+use metaheuristics::rate_control::RateControl;
+use metaheuristics::island_model::{IslandModel, Topology};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::metric_observers::MetricObserver;
+use metaheuristics::distribution_observers::DistributionObserver;
+use metaheuristics::individual::{Individual, Crossover, Mutate};
+use metaheuristics::observer::Observer;
+
+#[test]
+fn test_topology_destinations() {
+    let mut rng = thread_rng();
+    assert_eq!(Topology::Ring.destinations(3, 4, &mut rng), vec![0]);
+    assert_eq!(Topology::FullyConnected.destinations(1, 4, &mut rng), vec![0, 2, 3]);
+    for _ in 0..50 {
+        let destinations = Topology::Random.destinations(2, 4, &mut rng);
+        assert_eq!(destinations.len(), 1);
+        assert_ne!(destinations[0], 2);
+    }
+    assert!(Topology::Ring.destinations(0, 1, &mut rng).is_empty());
+}
+
+#[test]
+fn test_island_model_reports_per_island_statistics() {
+    let model = IslandModel::new(GeneticAlgorithm::new(10, 0.2, 0.8, 12), 3)
+        .with_topology(Topology::FullyConnected)
+        .with_migration(5, 2);
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![DistributionObserver::new()];
+
+    model.optimize(&mut archive, &mut observers);
+
+    assert!(archive.get_best().is_some());
+    assert_eq!(observers[0].distributions.len(), 13);
+    assert!(observers[0].distributions.iter().all(|population| population.len() == 30));
+
+    let mut observers = vec![MetricObserver::new()];
+    model.optimize(&mut archive, &mut observers);
+    for island in 0..3 {
+        assert_eq!(observers[0].values(&format!("island_{}_best", island)).len(), 12);
+        assert_eq!(observers[0].values(&format!("island_{}_mean", island)).len(), 12);
+    }
+}
+
+#[test]
+fn test_parallel_islands() {
+    let model = IslandModel::new(GeneticAlgorithm::new(20, 0.2, 0.8, 20), 4)
+        .with_topology(Topology::Random)
+        .with_migration(4, 1)
+        .parallel();
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    model.optimize(&mut archive, &mut observers);
+
+    let best = observers[0].values("island_0_best");
+    assert_eq!(best.len(), 20);
+    assert!(archive.get_best().is_some());
+}
+
+#[test]
+fn test_islands_use_steady_state_model_and_rate_control() {
+    let ga = GeneticAlgorithm::new(10, 0.5, 0.8, 8)
+        .with_steady_state(2, Replacement::Worst)
+        .with_mutation_control(RateControl::Linear { end: 0.0 });
+    let model = IslandModel::new(ga, 2).with_migration(3, 1);
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    model.optimize(&mut archive, &mut observers);
+
+    let mutation = observers[0].values("mutation_rate");
+    assert_eq!(mutation.len(), 8);
+    assert!(mutation[7].abs() < 1e-12);
+    // Replacing the worst member never makes an island's best worse.
+    let best = observers[0].values("island_0_best");
+    assert!(best.windows(2).all(|w| w[1] <= w[0]));
+}
+
+static EVALUATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// A sphere point that counts how often its fitness is computed.
+#[derive(Clone, Debug)]
+struct Counted {
+    x: f64,
+}
+
+impl Default for Counted {
+    fn default() -> Self {
+        Self { x: thread_rng().gen_range(-5.0..5.0) }
+    }
+}
+
+impl Individual for Counted {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        EVALUATIONS.fetch_add(1, Ordering::SeqCst);
+        self.x * self.x
+    }
+}
+
+impl Crossover for Counted {
+    fn crossover(&self, other: &Self, _rng: &mut ThreadRng) -> Self {
+        Self { x: 0.5 * (self.x + other.x) }
+    }
+}
+
+impl Mutate for Counted {
+    fn mutate(&mut self, rng: &mut ThreadRng) {
+        self.x += rng.gen_range(-0.1..0.1);
+    }
+}
+
+// Asks to stop once the given generation has been reported.
+struct StopAfter {
+    generation: usize,
+    seen: usize,
+}
+
+impl Observer<Counted> for StopAfter {
+    fn on_start(&mut self) {}
+
+    fn on_iteration(&mut self, iteration: usize, _population: &[Counted]) {
+        self.seen = iteration;
+    }
+
+    fn should_stop(&self) -> bool {
+        self.seen >= self.generation
+    }
+
+    fn on_finish(&mut self) {}
+}
+
+#[test]
+fn test_stop_requests_end_the_run_between_generations() {
+    for parallel in [false, true] {
+        let mut model = IslandModel::new(GeneticAlgorithm::<Counted>::new(10, 0.2, 0.8, 50), 2).with_migration(0, 0);
+        if parallel {
+            model = model.parallel();
+        }
+        let mut archive: BasicArchive<Counted> = BasicArchive::new(1);
+
+        EVALUATIONS.store(0, Ordering::SeqCst);
+        model.optimize(&mut archive, &mut [StopAfter { generation: usize::MAX, seen: 0 }]);
+        let full = EVALUATIONS.load(Ordering::SeqCst);
+
+        EVALUATIONS.store(0, Ordering::SeqCst);
+        let mut observers = vec![StopAfter { generation: 2, seen: 0 }];
+        model.optimize(&mut archive, &mut observers);
+        let stopped = EVALUATIONS.load(Ordering::SeqCst);
+
+        assert_eq!(observers[0].seen, 2);
+        assert!(stopped * 10 < full, "{} evaluations after a stop at generation 2, {} for the full run", stopped, full);
+    }
+}
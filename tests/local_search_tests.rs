@@ -0,0 +1,114 @@
+use rand::prelude::*;
+use metaheuristics::genetic_algorithm::{GeneticAlgorithm, Replacement};
+use metaheuristics::local_search::{LocalSearch, Learning, hill_climb, steepest_descent};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individual::Individual;
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::observer::Observer;
+use metaheuristics::island_model::IslandModel;
+use metaheuristics::novelty_search::{NoveltySearch, NoveltyArchive};
+
+// Jumps straight to the optimum of the sphere function.
+fn solve(individual: &NumericIndividual, _budget: usize, _rng: &mut ThreadRng) -> NumericIndividual {
+    NumericIndividual { genes: vec![0.0; individual.genes.len()] }
+}
+
+// Records the lowest fitness seen in any population.
+struct BestInPopulation {
+    best: f64,
+}
+
+impl Observer<NumericIndividual> for BestInPopulation {
+    fn on_start(&mut self) {}
+
+    fn on_iteration(&mut self, _iteration: usize, population: &[NumericIndividual]) {
+        for individual in population {
+            self.best = self.best.min(individual.fitness());
+        }
+    }
+
+    fn on_finish(&mut self) {}
+}
+
+#[test]
+fn test_hill_climbers_never_worsen() {
+    let mut rng = thread_rng();
+    for _ in 0..20 {
+        let start = NumericIndividual { genes: vec![3.0; 5] };
+        let climbed = hill_climb(&start, 100, &mut rng);
+        let descended = steepest_descent(&start, 100, 10, &mut rng);
+        assert!(climbed.fitness() < start.fitness());
+        assert!(descended.fitness() <= start.fitness());
+    }
+}
+
+#[test]
+fn test_learning_modes_decide_what_is_kept() {
+    let mut rng = thread_rng();
+    let offspring = NumericIndividual { genes: vec![1.0, 2.0] };
+
+    let lamarckian = LocalSearch::new(solve, 1.0, 10);
+    let (kept, score) = lamarckian.learn(offspring.clone(), &mut rng);
+    assert_eq!(kept.genes, vec![0.0, 0.0]);
+    assert_eq!(score, 0.0);
+
+    let baldwinian = LocalSearch::new(solve, 1.0, 10).with_learning(Learning::Baldwinian);
+    let (kept, score) = baldwinian.learn(offspring.clone(), &mut rng);
+    assert_eq!(kept.genes, offspring.genes);
+    assert_eq!(score, 0.0);
+
+    let never = LocalSearch::new(solve, 0.0, 10);
+    let (kept, score) = never.learn(offspring.clone(), &mut rng);
+    assert_eq!(kept.genes, offspring.genes);
+    assert_eq!(score, 5.0);
+}
+
+#[test]
+fn test_memetic_ga_writes_back_only_when_lamarckian() {
+    for steady_state in [false, true] {
+        for learning in [Learning::Lamarckian, Learning::Baldwinian] {
+            let mut ga: GeneticAlgorithm<NumericIndividual> = GeneticAlgorithm::new(10, 0.1, 0.8, 5)
+                .with_local_search(LocalSearch::new(solve, 1.0, 10).with_learning(learning));
+            if steady_state {
+                ga = ga.with_steady_state(2, Replacement::Worst);
+            }
+            let mut archive = BasicArchive::new(1);
+            let mut observers = vec![BestInPopulation { best: f64::INFINITY }];
+
+            ga.optimize(&mut archive, &mut observers);
+
+            let found = archive.get_best().unwrap().fitness() == 0.0;
+            assert_eq!(found, learning == Learning::Lamarckian);
+            assert_eq!(observers[0].best == 0.0, learning == Learning::Lamarckian);
+        }
+    }
+}
+
+#[test]
+fn test_hill_climber_improves_ga_offspring() {
+    let ga: GeneticAlgorithm<NumericIndividual> = GeneticAlgorithm::new(20, 0.1, 0.8, 10)
+        .with_local_search(LocalSearch::hill_climber(1.0, 50));
+    let mut archive = BasicArchive::new(1);
+    let mut observers: Vec<BestInPopulation> = Vec::new();
+
+    ga.optimize(&mut archive, &mut observers);
+
+    assert!(archive.get_best().unwrap().fitness() < 1.0);
+}
+
+#[test]
+fn test_island_model_and_novelty_search_apply_local_search() {
+    let ga = || -> GeneticAlgorithm<NumericIndividual> {
+        GeneticAlgorithm::new(10, 0.1, 0.8, 3).with_local_search(LocalSearch::new(solve, 1.0, 10))
+    };
+
+    let mut archive = BasicArchive::new(1);
+    IslandModel::new(ga(), 2).optimize(&mut archive, &mut Vec::<BestInPopulation>::new());
+    assert_eq!(archive.get_best().unwrap().fitness(), 0.0);
+
+    let mut archive = BasicArchive::new(1);
+    NoveltySearch::new(ga(), NoveltyArchive::new(3, 0.1, 10), |ind: &NumericIndividual| ind.genes.clone())
+        .optimize(&mut archive, &mut Vec::<BestInPopulation>::new());
+    assert_eq!(archive.get_best().unwrap().fitness(), 0.0);
+}
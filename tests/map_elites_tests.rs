@@ -0,0 +1,75 @@
+use std::sync::Arc;
+use metaheuristics::map_elites::{MapElites, MapElitesArchive, Tessellation};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::Individual;
+use metaheuristics::individuals::{NumericIndividual, TourIndividual};
+use metaheuristics::initializer::Initializer;
+use metaheuristics::metric_observers::MetricObserver;
+
+fn descriptor(individual: &NumericIndividual) -> Vec<f64> {
+    vec![individual.genes[0], individual.genes[1]]
+}
+
+#[test]
+fn test_map_elites_archive_keeps_best_per_cell() {
+    let tessellation = Tessellation::grid(vec![(-5.0, 5.0), (-5.0, 5.0)], vec![2, 2]);
+    let mut archive = MapElitesArchive::new(tessellation, descriptor);
+
+    archive.add(NumericIndividual { genes: vec![1.0, 1.0, 3.0] });
+    archive.add(NumericIndividual { genes: vec![2.0, 2.0, 0.0] });
+    archive.add(NumericIndividual { genes: vec![-1.0, 1.0, 0.0] });
+
+    assert_eq!(archive.len(), 2);
+    assert_eq!(archive.coverage(), 0.5);
+    assert_eq!(archive.get_best().unwrap().fitness(), 2.0);
+    assert_eq!(archive.get_cell(3).unwrap().genes, vec![2.0, 2.0, 0.0]);
+}
+
+#[test]
+fn test_map_elites_optimizer_reports_coverage() {
+    let tessellation = Tessellation::cvt(&[(-5.0, 5.0), (-5.0, 5.0)], 16, 1000, 5);
+    let grid = MapElitesArchive::new(tessellation, descriptor).with_qd_offset(100.0);
+    let map_elites = MapElites::new(grid, 20, 10, 50, 0.5, 1.0);
+
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    map_elites.optimize(&mut archive, &mut observers);
+
+    assert!(archive.get_best().is_some());
+    let coverage = observers[0].values("coverage");
+    assert_eq!(coverage.len(), 51);
+    assert!(coverage.windows(2).all(|w| w[0] <= w[1]));
+    assert!(observers[0].values("qd_score").iter().all(|&qd| qd > 0.0));
+}
+
+#[test]
+fn test_tessellations_reject_invalid_shapes() {
+    assert!(std::panic::catch_unwind(|| Tessellation::grid(vec![(0.0, 1.0), (0.0, 1.0)], vec![3, 0])).is_err());
+    assert!(std::panic::catch_unwind(|| Tessellation::cvt(&[(0.0, 1.0)], 0, 100, 1)).is_err());
+
+    let grid = Tessellation::grid(vec![(0.0, 1.0), (0.0, 1.0)], vec![2, 2]);
+    assert!(std::panic::catch_unwind(|| grid.cell_index(&[0.5])).is_err());
+}
+
+#[test]
+fn test_map_elites_starts_from_initializer() {
+    // Tours have no `Default`, so they can only be seeded by an initializer.
+    let distances: Arc<Vec<Vec<f64>>> =
+        Arc::new((0..5).map(|i: i32| (0..5).map(|j: i32| (i - j).abs() as f64).collect()).collect());
+    let matrix = Arc::clone(&distances);
+    let initializer = Initializer::from_fn(move |rng| TourIndividual::random(Arc::clone(&matrix), rng));
+    let first_city = |tour: &TourIndividual| vec![tour.tour[0] as f64];
+    let grid = MapElitesArchive::new(Tessellation::grid(vec![(0.0, 5.0)], vec![5]), first_city);
+    let map_elites = MapElites::from_initializer(initializer, grid, 10, 10, 20, 0.5, 1.0);
+
+    let mut archive: BasicArchive<TourIndividual> = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    map_elites.optimize(&mut archive, &mut observers);
+
+    assert!(observers[0].values("coverage")[0] > 0.0);
+    let best = archive.get_best().unwrap();
+    assert_eq!(best.length, TourIndividual::from_permutation(best.tour.clone(), distances).length);
+}
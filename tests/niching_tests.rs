@@ -0,0 +1,92 @@
+use rand::prelude::*;
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::initializer::Initializer;
+use metaheuristics::niching::{Niching, NichingMethod};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::BasicArchive;
+use metaheuristics::individual::{Individual, Crossover, Mutate};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::distribution_observers::DistributionObserver;
+
+// Deb's equal-maxima function on [0, 1], negated: five optima of -1 at
+// x = 0.1, 0.3, 0.5, 0.7 and 0.9.
+#[derive(Clone, Debug)]
+struct EqualMaxima {
+    x: f64,
+}
+
+impl Individual for EqualMaxima {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        -(5.0 * std::f64::consts::PI * self.x).sin().powi(6)
+    }
+}
+
+impl Crossover for EqualMaxima {
+    fn crossover(&self, other: &Self, rng: &mut ThreadRng) -> Self {
+        let weight = rng.gen::<f64>();
+        Self { x: weight * self.x + (1.0 - weight) * other.x }
+    }
+}
+
+impl Mutate for EqualMaxima {
+    fn mutate(&mut self, rng: &mut ThreadRng) {
+        self.x = (self.x + rng.gen_range(-0.02..0.02)).clamp(0.0, 1.0);
+    }
+}
+
+impl Default for EqualMaxima {
+    fn default() -> Self {
+        Self { x: thread_rng().gen() }
+    }
+}
+
+fn optima_found(population: &[EqualMaxima]) -> usize {
+    [0.1, 0.3, 0.5, 0.7, 0.9]
+        .iter()
+        .filter(|&&peak| population.iter().any(|ind| (ind.x - peak).abs() < 0.05 && ind.fitness() < -0.8))
+        .count()
+}
+
+#[test]
+fn test_clearing_keeps_niche_winners() {
+    let population: Vec<NumericIndividual> = [0.0, 0.1, 5.0, 5.1]
+        .iter()
+        .map(|&x| NumericIndividual { genes: vec![x] })
+        .collect();
+    let scores = vec![1.0, 2.0, 3.0, 4.0];
+
+    let clearing = Niching::euclidean(NichingMethod::Clearing { radius: 1.0, capacity: 1 });
+    assert_eq!(clearing.adjust_scores(&population, &scores), vec![1.0, 4.0, 3.0, 4.0]);
+
+    let sharing = Niching::euclidean(NichingMethod::FitnessSharing { radius: 1.0, alpha: 1.0 });
+    let shared = sharing.adjust_scores(&population, &scores);
+    assert!(shared.iter().zip(&scores).all(|(s, o)| s >= o));
+    assert!(shared[0] < shared[1] && shared[2] < shared[3]);
+}
+
+#[test]
+fn test_niching_methods_cover_several_optima() {
+    let methods = [
+        NichingMethod::FitnessSharing { radius: 0.1, alpha: 1.0 },
+        NichingMethod::Clearing { radius: 0.1, capacity: 5 },
+        NichingMethod::DeterministicCrowding,
+        NichingMethod::RestrictedTournament { window: 10 },
+    ];
+
+    for method in methods {
+        // Spread the start evenly so every peak has a fair share of the population.
+        let start: Vec<EqualMaxima> = (0..100).map(|i| EqualMaxima { x: i as f64 / 99.0 }).collect();
+        let ga = GeneticAlgorithm::from_initializer(Initializer::from_population(start), 100, 0.3, 0.5, 60)
+            .with_niching(Niching::new(method, |a: &EqualMaxima, b: &EqualMaxima| (a.x - b.x).abs()));
+        let mut archive: BasicArchive<EqualMaxima> = BasicArchive::new(5);
+        let mut observers = vec![DistributionObserver::new()];
+
+        ga.optimize(&mut archive, &mut observers);
+
+        let last = observers[0].distributions.last().unwrap();
+        // Runs typically keep four or five peaks.
+        assert!(optima_found(last) >= 3, "{:?} found {} optima", method, optima_found(last));
+    }
+}
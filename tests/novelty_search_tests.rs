@@ -0,0 +1,39 @@
+use metaheuristics::novelty_search::{NoveltyArchive, NoveltySearch};
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::metric_observers::MetricObserver;
+
+#[test]
+fn test_novelty_is_mean_distance_to_nearest_neighbours() {
+    let mut novelty_archive = NoveltyArchive::new(2, 1.0, 10);
+    let population = vec![vec![0.0], vec![1.0], vec![3.0]];
+
+    assert_eq!(novelty_archive.novelty(&population[0], &population, 0), 2.0);
+
+    assert!(novelty_archive.consider(vec![-1.0], 1.5));
+    assert!(!novelty_archive.consider(vec![5.0], 0.5));
+    assert_eq!(novelty_archive.novelty(&population[0], &population, 0), 1.0);
+}
+
+#[test]
+fn test_novelty_search_numeric_individual() {
+    let ga = GeneticAlgorithm::new(30, 0.5, 0.8, 20);
+    let novelty_search = NoveltySearch::new(
+        ga,
+        NoveltyArchive::new(5, 0.5, 100),
+        |individual: &NumericIndividual| individual.genes[..2].to_vec(),
+    )
+    .with_fitness_weight(0.2);
+
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    novelty_search.optimize(&mut archive, &mut observers);
+
+    assert!(archive.get_best().is_some());
+    let archive_sizes = observers[0].values("novelty_archive_size");
+    assert_eq!(archive_sizes.len(), 20);
+    assert!(archive_sizes.last().unwrap() > &0.0);
+}
@@ -1,11 +1,9 @@
 use metaheuristics::particle_swarm::ParticleSwarm;
 use metaheuristics::optimizer::Optimizer;
-use metaheuristics::observer::Observer;
 use metaheuristics::archive::{BasicArchive, Archive};
 use metaheuristics::Individual;
 use metaheuristics::individuals::ParticleIndividual;
 use metaheuristics::logging_observers::LoggingObserver;
-use metaheuristics::distribution_observers::DistributionObserver;
 
 #[test]
 fn test_particle_swarm() {
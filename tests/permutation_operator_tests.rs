@@ -0,0 +1,112 @@
+use rand::prelude::*;
+use std::sync::Arc;
+use metaheuristics::individual::{CrossoverOperator, MutationOperator, Permutation};
+use metaheuristics::permutation_operators::{
+    PartiallyMapped, OrderCrossover, CycleCrossover, EdgeRecombination,
+    SwapMutation, InsertionMutation, InversionMutation, ScrambleMutation,
+};
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individuals::TourIndividual;
+use metaheuristics::initializer::Initializer;
+use metaheuristics::logging_observers::LoggingObserver;
+
+// Cities evenly spaced on a unit circle; the optimal tour visits them in order.
+fn circle(cities: usize) -> Arc<Vec<Vec<f64>>> {
+    let points: Vec<(f64, f64)> = (0..cities)
+        .map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * i as f64 / cities as f64;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+    Arc::new(
+        points
+            .iter()
+            .map(|a| points.iter().map(|b| ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()).collect())
+            .collect(),
+    )
+}
+
+fn is_permutation(tour: &[usize], cities: usize) -> bool {
+    let mut sorted = tour.to_vec();
+    sorted.sort_unstable();
+    sorted == (0..cities).collect::<Vec<_>>()
+}
+
+#[test]
+fn test_permutation_operators_produce_valid_tours() {
+    let mut rng = thread_rng();
+    let distances = circle(12);
+    let crossovers: Vec<Box<dyn CrossoverOperator<TourIndividual>>> = vec![
+        Box::new(PartiallyMapped),
+        Box::new(OrderCrossover),
+        Box::new(CycleCrossover),
+        Box::new(EdgeRecombination),
+    ];
+    let mutations: Vec<Box<dyn MutationOperator<TourIndividual>>> = vec![
+        Box::new(SwapMutation),
+        Box::new(InsertionMutation),
+        Box::new(InversionMutation),
+        Box::new(ScrambleMutation),
+    ];
+
+    for _ in 0..100 {
+        let parent1 = TourIndividual::random(distances.clone(), &mut rng);
+        let parent2 = TourIndividual::random(distances.clone(), &mut rng);
+        for operator in &crossovers {
+            let mut child = operator.crossover(&[&parent1, &parent2], &mut rng);
+            assert!(is_permutation(&child.tour, 12));
+            for mutation in &mutations {
+                mutation.mutate(&mut child, 0.5, &mut rng);
+                assert!(is_permutation(&child.tour, 12));
+                let expected = TourIndividual::from_permutation(child.tour.clone(), distances.clone()).length;
+                assert!((child.length - expected).abs() < 1e-9);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cycle_crossover_keeps_positions() {
+    let mut rng = thread_rng();
+    let distances = circle(8);
+    let parent1 = TourIndividual::from_permutation(vec![0, 1, 2, 3, 4, 5, 6, 7], distances.clone());
+    let parent2 = TourIndividual::from_permutation(vec![7, 6, 5, 4, 3, 2, 1, 0], distances);
+
+    let child = CycleCrossover.crossover(&[&parent1, &parent2], &mut rng);
+    for (i, city) in child.permutation().iter().enumerate() {
+        assert!(*city == parent1.tour[i] || *city == parent2.tour[i]);
+    }
+}
+
+#[test]
+fn test_genetic_algorithm_and_annealing_solve_circle_tsp() {
+    let distances = circle(10);
+    let optimum = 20.0 * (std::f64::consts::PI / 10.0).sin();
+
+    let ga_distances = distances.clone();
+    let ga = GeneticAlgorithm::from_initializer(
+        Initializer::from_fn(move |rng| TourIndividual::random(ga_distances.clone(), rng)),
+        60,
+        0.3,
+        0.9,
+        150,
+    )
+    .with_crossover_operator(EdgeRecombination)
+    .with_mutation_operator(InversionMutation);
+    let mut archive: BasicArchive<TourIndividual> = BasicArchive::new(5);
+    let mut observers = vec![LoggingObserver::new(false, false, false)];
+    ga.optimize(&mut archive, &mut observers);
+    assert!(archive.get_best().unwrap().length < optimum * 1.2);
+
+    let start = TourIndividual::random(distances.clone(), &mut thread_rng());
+    let sa = SimulatedAnnealing::from_initializer(Initializer::from_population(vec![start.clone()]), 1.0, 0.002, 5000);
+    let mut archive: BasicArchive<TourIndividual> = BasicArchive::new(5);
+    sa.optimize(&mut archive, &mut observers);
+    let best = archive.get_best().unwrap();
+    assert!(is_permutation(&best.tour, 10));
+    assert!(best.length < start.length);
+    assert!(best.length < optimum * 1.2, "{} vs {}", best.length, optimum);
+}
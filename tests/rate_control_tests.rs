@@ -0,0 +1,61 @@
+use metaheuristics::genetic_algorithm::{GeneticAlgorithm, Replacement};
+use metaheuristics::rate_control::{RateControl, SelfAdaptive};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::metric_observers::MetricObserver;
+
+#[test]
+fn test_rate_control_schedules() {
+    assert_eq!(RateControl::Fixed.next(0.3, 0.9, 0.5, None), 0.3);
+    assert!((RateControl::Linear { end: 0.1 }.next(0.5, 0.5, 0.5, None) - 0.3).abs() < 1e-12);
+    assert!((RateControl::Exponential { end: 0.01 }.next(1.0, 1.0, 0.5, None) - 0.1).abs() < 1e-12);
+
+    let rule = RateControl::success_based();
+    assert!((rule.next(0.1, 0.1, 0.0, Some(0.5)) - 0.12).abs() < 1e-12);
+    assert!((rule.next(0.1, 0.1, 0.0, Some(0.0)) - 0.1 / 1.2).abs() < 1e-12);
+    assert_eq!(rule.next(0.1, 0.9, 0.0, Some(1.0)), 1.0);
+    assert_eq!(rule.next(0.1, 0.05, 0.0, None), 0.05);
+}
+
+#[test]
+fn test_scheduled_rates_are_reported() {
+    let ga = GeneticAlgorithm::new(20, 0.5, 0.9, 10)
+        .with_mutation_control(RateControl::Linear { end: 0.0 })
+        .with_crossover_control(RateControl::success_based());
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    let mutation = observers[0].values("mutation_rate");
+    assert_eq!(mutation.len(), 10);
+    assert!(mutation.windows(2).all(|w| w[1] < w[0]));
+    assert!(mutation[9].abs() < 1e-12);
+
+    let crossover = observers[0].values("crossover_rate");
+    assert_eq!(crossover.len(), 10);
+    assert!(crossover.iter().all(|&rate| (0.001..=1.0).contains(&rate)));
+}
+
+#[test]
+fn test_self_adaptive_rates_evolve_with_population() {
+    let ga = GeneticAlgorithm::<SelfAdaptive<NumericIndividual>>::new(30, 0.0, 0.0, 15)
+        .with_self_adaptation(0.3);
+    let mut archive: BasicArchive<SelfAdaptive<NumericIndividual>> = BasicArchive::new(5);
+    let mut observers = vec![MetricObserver::new()];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    let best = archive.get_best().unwrap();
+    assert!((0.0..=1.0).contains(&best.rates.mutation));
+    assert!((0.0..=1.0).contains(&best.rates.crossover));
+    let mutation = observers[0].values("mutation_rate");
+    assert_eq!(mutation.len(), 15);
+    assert!(mutation.iter().all(|&rate| rate > 0.0 && rate < 1.0));
+
+    let steady = GeneticAlgorithm::<SelfAdaptive<NumericIndividual>>::new(20, 0.0, 0.0, 5)
+        .with_self_adaptation(0.3)
+        .with_steady_state(2, Replacement::Worst);
+    steady.optimize(&mut archive, &mut observers);
+}
@@ -0,0 +1,79 @@
+use rand::prelude::*;
+use metaheuristics::individual::CrossoverOperator;
+use metaheuristics::real_crossover::{Sbx, BlxAlpha, Arithmetic, Intermediate, UniformCrossover, MultiPoint, Undx};
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::logging_observers::LoggingObserver;
+
+fn individual(genes: Vec<f64>) -> NumericIndividual {
+    NumericIndividual { genes }
+}
+
+#[test]
+fn test_bounded_real_crossovers_stay_within_bounds() {
+    let mut rng = thread_rng();
+    let bounds = vec![(-1.0, 1.0); 6];
+    let parent1 = individual(vec![-0.99, -0.5, 0.0, 0.5, 0.99, 1.0]);
+    let parent2 = individual(vec![0.99, 0.5, -1.0, 0.2, -0.99, -1.0]);
+    let parent3 = individual(vec![0.0, 1.0, 1.0, -1.0, 0.0, 0.5]);
+    let operators: Vec<Box<dyn CrossoverOperator<NumericIndividual>>> = vec![
+        Box::new(Sbx::new(2.0).with_bounds(bounds.clone())),
+        Box::new(BlxAlpha::new(0.5).with_bounds(bounds.clone())),
+        Box::new(Intermediate::new(0.25).with_bounds(bounds.clone())),
+        Box::new(Undx::new().with_bounds(bounds.clone())),
+    ];
+
+    for operator in &operators {
+        for _ in 0..200 {
+            let child = operator.crossover(&[&parent1, &parent2, &parent3], &mut rng);
+            assert_eq!(child.genes.len(), 6);
+            assert!(child.genes.iter().all(|&x| (-1.0..=1.0).contains(&x)));
+        }
+    }
+}
+
+#[test]
+fn test_recombining_crossovers_take_genes_from_parents() {
+    let mut rng = thread_rng();
+    let parent1 = individual(vec![0.0; 8]);
+    let parent2 = individual(vec![1.0; 8]);
+
+    let child = Arithmetic::new(0.25).crossover(&[&parent1, &parent2], &mut rng);
+    assert_eq!(child.genes, vec![0.75; 8]);
+
+    let child = UniformCrossover::default().crossover(&[&parent1, &parent2], &mut rng);
+    assert!(child.genes.iter().all(|&x| x == 0.0 || x == 1.0));
+
+    let child = MultiPoint::new(3).crossover(&[&parent1, &parent2], &mut rng);
+    assert_eq!(child.genes[0], 0.0);
+    let switches = child.genes.windows(2).filter(|w| w[0] != w[1]).count();
+    assert_eq!(switches, 3);
+}
+
+#[test]
+fn test_undx_with_collinear_parents_stays_on_line() {
+    let mut rng = thread_rng();
+    let parent1 = individual(vec![0.0, 0.0, 0.0]);
+    let parent2 = individual(vec![2.0, 2.0, 2.0]);
+    let parent3 = individual(vec![5.0, 5.0, 5.0]);
+
+    let child = Undx::new().crossover(&[&parent1, &parent2, &parent3], &mut rng);
+    assert!((child.genes[0] - child.genes[1]).abs() < 1e-9);
+    assert!((child.genes[1] - child.genes[2]).abs() < 1e-9);
+}
+
+#[test]
+fn test_genetic_algorithm_with_sbx() {
+    let ga = GeneticAlgorithm::new(40, 0.1, 0.9, 30).with_crossover_operator(Sbx::new(15.0));
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![LoggingObserver::new(true, false, false)];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    assert!(archive.get_best().is_some());
+
+    let ga = GeneticAlgorithm::new(40, 0.1, 0.9, 10).with_crossover_operator(Undx::new());
+    ga.optimize(&mut archive, &mut observers);
+}
@@ -0,0 +1,71 @@
+use rand::prelude::*;
+use metaheuristics::individual::MutationOperator;
+use metaheuristics::bounds::BoundHandling;
+use metaheuristics::real_mutation::{
+    GaussianMutation, CauchyMutation, PolynomialMutation, UniformResetMutation, NonUniformMutation,
+};
+use metaheuristics::real_crossover::Sbx;
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::logging_observers::LoggingObserver;
+
+#[test]
+fn test_bound_handling() {
+    let mut rng = thread_rng();
+    let bound = (0.0, 1.0);
+
+    assert_eq!(BoundHandling::Clip.apply(1.5, bound, &mut rng), 1.0);
+    assert_eq!(BoundHandling::Reflect.apply(1.25, bound, &mut rng), 0.75);
+    assert_eq!(BoundHandling::Reflect.apply(-0.25, bound, &mut rng), 0.25);
+    assert_eq!(BoundHandling::Reflect.apply(2.25, bound, &mut rng), 0.25);
+    assert_eq!(BoundHandling::Wrap.apply(1.25, bound, &mut rng), 0.25);
+    assert_eq!(BoundHandling::Wrap.apply(-0.25, bound, &mut rng), 0.75);
+    assert!((0.0..=1.0).contains(&BoundHandling::Resample.apply(7.0, bound, &mut rng)));
+    assert_eq!(BoundHandling::Resample.apply(0.5, bound, &mut rng), 0.5);
+}
+
+#[test]
+fn test_mutation_operators_respect_bounds_and_gene_probability() {
+    let mut rng = thread_rng();
+    let bounds = vec![(-1.0, 1.0); 10];
+    let operators: Vec<Box<dyn MutationOperator<NumericIndividual>>> = vec![
+        Box::new(GaussianMutation::new(0.5).with_gene_probability(1.0).with_bounds(bounds.clone(), BoundHandling::Reflect)),
+        Box::new(GaussianMutation::new(0.5).with_gene_probability(1.0).with_bounds(bounds.clone(), BoundHandling::Resample)),
+        Box::new(CauchyMutation::new(1.0).with_gene_probability(1.0).with_bounds(bounds.clone(), BoundHandling::Wrap)),
+        Box::new(PolynomialMutation::new(20.0, bounds.clone()).with_gene_probability(1.0)),
+        Box::new(UniformResetMutation::new(bounds.clone()).with_gene_probability(1.0)),
+        Box::new(NonUniformMutation::new(2.0, bounds.clone()).with_gene_probability(1.0)),
+    ];
+
+    for operator in &operators {
+        for _ in 0..100 {
+            let mut individual = NumericIndividual { genes: vec![0.9; 10] };
+            operator.mutate(&mut individual, 0.5, &mut rng);
+            assert!(individual.genes.iter().all(|x| (-1.0..=1.0).contains(x)));
+            assert!(individual.genes.iter().any(|&x| x != 0.9));
+        }
+    }
+
+    let mut individual = NumericIndividual { genes: vec![0.9; 10] };
+    GaussianMutation::new(1.0).with_gene_probability(0.0).mutate(&mut individual, 0.0, &mut rng);
+    assert_eq!(individual.genes, vec![0.9; 10]);
+
+    NonUniformMutation::new(2.0, bounds).with_gene_probability(1.0).mutate(&mut individual, 1.0, &mut rng);
+    assert_eq!(individual.genes, vec![0.9; 10]);
+}
+
+#[test]
+fn test_genetic_algorithm_with_mutation_operator() {
+    let bounds = vec![(-5.0, 5.0); 5];
+    let ga = GeneticAlgorithm::new(40, 1.0, 0.9, 30)
+        .with_crossover_operator(Sbx::new(15.0).with_bounds(bounds.clone()))
+        .with_mutation_operator(PolynomialMutation::new(20.0, bounds));
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![LoggingObserver::new(true, false, false)];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    assert!(archive.get_best().is_some());
+}
@@ -0,0 +1,128 @@
+use rand::prelude::*;
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::restarts::{RestartWrapper, RestartReason};
+use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individual::{Individual, Crossover, Mutate};
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::observer::Observer;
+
+// Every genome has the same fitness, so runs stagnate and the fitness spread
+// is zero from the start.
+#[derive(Clone, Debug, Default)]
+struct Plateau {
+    x: f64,
+}
+
+impl Individual for Plateau {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        1.0
+    }
+}
+
+impl Crossover for Plateau {
+    fn crossover(&self, other: &Self, _rng: &mut ThreadRng) -> Self {
+        Self { x: (self.x + other.x) / 2.0 }
+    }
+}
+
+impl Mutate for Plateau {
+    fn mutate(&mut self, rng: &mut ThreadRng) {
+        self.x += rng.gen_range(-1.0..1.0);
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    starts: usize,
+    finishes: usize,
+    iterations: Vec<usize>,
+    individuals: usize,
+    metrics: Vec<(String, f64)>,
+    stop_after: Option<usize>,
+}
+
+impl Recorder {
+    fn values(&self, name: &str) -> Vec<f64> {
+        self.metrics.iter().filter(|(metric, _)| metric == name).map(|&(_, value)| value).collect()
+    }
+}
+
+impl<I: Individual> Observer<I> for Recorder {
+    fn on_start(&mut self) {
+        self.starts += 1;
+    }
+
+    fn on_iteration(&mut self, iteration: usize, population: &[I]) {
+        self.iterations.push(iteration);
+        self.individuals += population.len();
+    }
+
+    fn on_metric(&mut self, _iteration: usize, name: &str, value: f64) {
+        self.metrics.push((name.to_string(), value));
+    }
+
+    fn should_stop(&self) -> bool {
+        self.stop_after.is_some_and(|limit| self.iterations.len() >= limit)
+    }
+
+    fn on_finish(&mut self) {
+        self.finishes += 1;
+    }
+}
+
+#[test]
+fn test_stagnation_and_diversity_collapse_trigger_restarts() {
+    let configurations = [
+        (RestartReason::Stagnation, 5, 0.0),
+        (RestartReason::DiversityCollapse, 0, 1e-6),
+    ];
+    for (reason, window, threshold) in configurations {
+        let wrapper = RestartWrapper::new(|size| GeneticAlgorithm::<Plateau>::new(size, 0.5, 0.5, 1000), 4, 100_000)
+            .with_stagnation_window(window)
+            .with_diversity_threshold(threshold)
+            .with_population_growth(2.0)
+            .with_max_restarts(3);
+        let mut archive = BasicArchive::new(1);
+        let mut observers = vec![Recorder::default()];
+
+        wrapper.optimize(&mut archive, &mut observers);
+
+        let recorder = &observers[0];
+        assert_eq!((recorder.starts, recorder.finishes), (1, 1));
+        assert_eq!(recorder.values("restart"), vec![0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(recorder.values("population_size"), vec![4.0, 8.0, 16.0, 32.0]);
+        assert_eq!(recorder.values("restart_reason"), vec![reason as usize as f64; 3]);
+        assert!(recorder.iterations.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(recorder.iterations.len() < 100);
+        assert!(archive.get_best().is_some());
+    }
+}
+
+#[test]
+fn test_runs_share_the_evaluation_budget() {
+    let wrapper = RestartWrapper::new(|size| GeneticAlgorithm::<NumericIndividual>::new(size, 0.1, 0.8, 1000), 10, 200)
+        .with_stagnation_window(0);
+    let mut archive = BasicArchive::new(1);
+    let mut observers = vec![Recorder::default()];
+
+    wrapper.optimize(&mut archive, &mut observers);
+
+    assert_eq!(observers[0].values("restart"), vec![0.0]);
+    assert!((200..=210).contains(&observers[0].individuals));
+}
+
+#[test]
+fn test_optimizers_stop_when_an_observer_asks() {
+    let sa: SimulatedAnnealing<NumericIndividual> = SimulatedAnnealing::new(10.0, 0.01, 1000);
+    let mut archive = BasicArchive::new(1);
+    let mut observers = vec![Recorder { stop_after: Some(20), ..Recorder::default() }];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    assert_eq!(observers[0].iterations.len(), 20);
+    assert_eq!(observers[0].finishes, 1);
+}
@@ -0,0 +1,103 @@
+use rand::prelude::*;
+use metaheuristics::sampling::{Sampler, UniformSampler, LatinHypercube, Halton, Sobol, opposition_based};
+use metaheuristics::initializer::Initializer;
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::particle_swarm::ParticleSwarm;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::BasicArchive;
+use metaheuristics::individuals::{NumericIndividual, ParticleIndividual};
+use metaheuristics::distribution_observers::DistributionObserver;
+
+/// Checks that the 1-D projection of `points` puts one point in each of
+/// `points.len()` equal strata of every dimension.
+fn is_stratified(points: &[Vec<f64>], bounds: &[(f64, f64)]) -> bool {
+    let n = points.len();
+    bounds.iter().enumerate().all(|(d, &(low, high))| {
+        let mut hits = vec![0; n];
+        for point in points {
+            let stratum = ((point[d] - low) / (high - low) * n as f64) as usize;
+            hits[stratum.min(n - 1)] += 1;
+        }
+        hits.iter().all(|&count| count == 1)
+    })
+}
+
+#[test]
+fn test_samplers_stay_within_bounds() {
+    let mut rng = thread_rng();
+    let bounds = vec![(-5.0, 5.0), (0.0, 1.0), (10.0, 20.0)];
+    let samplers: Vec<Box<dyn Sampler>> = vec![
+        Box::new(UniformSampler),
+        Box::new(LatinHypercube),
+        Box::new(Halton::shifted()),
+        Box::new(Sobol::shifted()),
+    ];
+
+    for sampler in &samplers {
+        let points = sampler.sample(50, &bounds, &mut rng);
+        assert_eq!(points.len(), 50);
+        for point in &points {
+            assert!(point.iter().zip(&bounds).all(|(&x, &(low, high))| x >= low && x < high));
+        }
+    }
+}
+
+#[test]
+fn test_space_filling_samplers_are_stratified() {
+    let mut rng = thread_rng();
+    let bounds = vec![(-1.0, 1.0); 21];
+
+    assert!(is_stratified(&LatinHypercube.sample(37, &bounds, &mut rng), &bounds));
+
+    // Every 1-D projection of the first 2^k Sobol points after the origin,
+    // plus the origin, is a perfect stratification.
+    let mut points = Sobol::new().sample(63, &bounds, &mut rng);
+    points.push(vec![-1.0; 21]);
+    assert!(is_stratified(&points, &bounds));
+
+    let halton = Halton::new().sample(3, &[(0.0, 1.0), (0.0, 1.0)], &mut rng);
+    let expected = [[0.5, 1.0 / 3.0], [0.25, 2.0 / 3.0], [0.75, 1.0 / 9.0]];
+    for (point, expected) in halton.iter().zip(expected) {
+        assert!(point.iter().zip(expected).all(|(x, y)| (x - y).abs() < 1e-12));
+    }
+}
+
+#[test]
+fn test_opposition_based_keeps_fitter_points() {
+    let mut rng = thread_rng();
+    let bounds = vec![(0.0, 10.0)];
+    let points = opposition_based(&UniformSampler, 20, &bounds, |x| x[0], &mut rng);
+
+    assert_eq!(points.len(), 20);
+    assert!(points.iter().all(|x| x[0] <= 5.0));
+}
+
+#[test]
+fn test_sampled_initializers_with_genetic_algorithm_and_swarm() {
+    let bounds = vec![(-2.0, 2.0); 8];
+    let ga = GeneticAlgorithm::from_initializer(
+        Initializer::<NumericIndividual>::sampled(LatinHypercube, bounds.clone()),
+        16,
+        0.1,
+        0.8,
+        3,
+    );
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+    let mut observers = vec![DistributionObserver::new()];
+    ga.optimize(&mut archive, &mut observers);
+
+    let initial: Vec<Vec<f64>> = observers[0].distributions[0].iter().map(|ind| ind.genes.clone()).collect();
+    assert!(is_stratified(&initial, &bounds));
+
+    let sphere = |position: &Vec<f64>| -> f64 { position.iter().map(|&x| x * x).sum() };
+    let pso = ParticleSwarm::new(16, 3, 5, 0.5, 1.5, 1.5, sphere)
+        .with_sampler(Sobol::shifted(), vec![(-1.0, 1.0); 3])
+        .with_opposition();
+    let mut archive: BasicArchive<ParticleIndividual> = BasicArchive::new(5);
+    let mut observers = vec![DistributionObserver::new()];
+    pso.optimize(&mut archive, &mut observers);
+
+    assert!(observers[0].distributions[0]
+        .iter()
+        .all(|particle| particle.position.iter().all(|&x| (-1.0..=1.0).contains(&x))));
+}
@@ -0,0 +1,68 @@
+use rand::prelude::*;
+use metaheuristics::selection::{
+    Selection, RouletteWheel, Tournament, LinearRank, ExponentialRank,
+    StochasticUniversalSampling, Truncation, Boltzmann,
+};
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::Individual;
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::logging_observers::LoggingObserver;
+
+fn selection_counts<S: Selection + ?Sized>(selection: &S, scores: &[f64], draws: usize) -> Vec<usize> {
+    let mut rng = thread_rng();
+    let mut counts = vec![0; scores.len()];
+    for index in selection.select(scores, draws, &mut rng) {
+        counts[index] += 1;
+    }
+    counts
+}
+
+#[test]
+fn test_selection_operators_favour_lower_negative_scores() {
+    let scores = [-1.0, -10.0, 5.0, -3.0];
+    let operators: Vec<Box<dyn Selection>> = vec![
+        Box::new(RouletteWheel),
+        Box::new(Tournament::new(3)),
+        Box::new(LinearRank::new(2.0)),
+        Box::new(ExponentialRank::new(0.5)),
+        Box::new(StochasticUniversalSampling),
+        Box::new(Boltzmann::new(2.0)),
+    ];
+
+    for operator in &operators {
+        let counts = selection_counts(operator.as_ref(), &scores, 4000);
+        assert_eq!(counts.iter().sum::<usize>(), 4000);
+        assert!(counts[1] > counts[3]);
+        assert!(counts[3] > counts[2]);
+    }
+}
+
+#[test]
+fn test_truncation_and_sus_selection_counts() {
+    let scores = [4.0, 3.0, 2.0, 1.0];
+
+    let counts = selection_counts(&Truncation::new(0.5), &scores, 1000);
+    assert_eq!(counts[0] + counts[1], 0);
+    assert!(counts[2] > 0 && counts[3] > 0);
+
+    // Weights 0, 1, 2, 3: a single spin of SUS yields exact expected counts.
+    let counts = selection_counts(&StochasticUniversalSampling, &scores, 6);
+    assert_eq!(counts, vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn test_genetic_algorithm_with_tournament_selection() {
+    let ga = GeneticAlgorithm::new(50, 0.2, 0.8, 30).with_selection(Tournament::new(3));
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+
+    let mut observers = vec![
+        LoggingObserver::new(true, false, false),
+    ];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    assert!(archive.get_best().is_some());
+    assert!(archive.get_best().unwrap().fitness() < 25.0);
+}
@@ -0,0 +1,88 @@
+use std::thread;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use metaheuristics::genetic_algorithm::GeneticAlgorithm;
+use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::shared_archive::SharedArchive;
+use metaheuristics::Individual;
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::metric_observers::MetricObserver;
+
+#[test]
+fn test_shared_archive_across_threads() {
+    let shared = SharedArchive::new(BasicArchive::<NumericIndividual>::new(10));
+
+    thread::scope(|scope| {
+        for _ in 0..3 {
+            let mut archive = shared.clone();
+            scope.spawn(move || {
+                let ga = GeneticAlgorithm::new(20, 0.1, 0.8, 10);
+                ga.optimize(&mut archive, &mut [MetricObserver::new()]);
+            });
+        }
+
+        let mut archive = shared.clone();
+        scope.spawn(move || {
+            let sa = SimulatedAnnealing::new(10.0, 0.01, 100);
+            sa.optimize(&mut archive, &mut [MetricObserver::new()]);
+        });
+    });
+
+    let snapshot = shared.snapshot();
+    assert_eq!(snapshot.len(), 10);
+    assert!(snapshot.windows(2).all(|w| w[0].fitness() <= w[1].fitness()));
+
+    let mut handle = shared.clone();
+    handle.refresh();
+    assert_eq!(handle.get_best().unwrap().fitness(), snapshot[0].fitness());
+    drop(handle);
+
+    let archive = shared.into_inner().unwrap();
+    assert_eq!(archive.solutions().len(), 10);
+}
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+// A solution that counts how often it is cloned.
+#[derive(Debug)]
+struct Counted {
+    score: f64,
+}
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+        Self { score: self.score }
+    }
+}
+
+impl Individual for Counted {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        self.score
+    }
+}
+
+#[test]
+fn test_shared_archive_clones_best_only_on_improvement() {
+    let mut shared = SharedArchive::new(BasicArchive::<Counted>::new(5));
+    let mut other = shared.clone();
+
+    shared.add(Counted { score: 1.0 });
+    for score in 2..100 {
+        shared.add(Counted { score: score as f64 });
+    }
+    assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+
+    shared.add(Counted { score: 0.5 });
+    assert_eq!(CLONES.load(Ordering::SeqCst), 2);
+    assert_eq!(shared.get_best().unwrap().score, 0.5);
+
+    // Another handle's improvement shows up after a refresh.
+    other.add(Counted { score: 0.1 });
+    assert_eq!(shared.get_best().unwrap().score, 0.5);
+    shared.refresh();
+    assert_eq!(shared.get_best().unwrap().score, 0.1);
+}
@@ -1,13 +1,15 @@
+use rand::prelude::*;
 use metaheuristics::simulated_annealing::SimulatedAnnealing;
+use metaheuristics::acceptance::Acceptance;
+use metaheuristics::cooling::CoolingSchedule;
 use metaheuristics::optimizer::Optimizer;
-use metaheuristics::observer::Observer;
 use metaheuristics::archive::{BasicArchive, Archive};
 use metaheuristics::Individual;
 use metaheuristics::individual::Neighbor;
 use metaheuristics::individuals::NumericIndividual;
 use metaheuristics::logging_observers::LoggingObserver;
-use metaheuristics::distribution_observers::DistributionObserver;
-use rand::prelude::*;
+use metaheuristics::metric_observers::MetricObserver;
+use metaheuristics::observer::Observer;
 
 
 #[test]
@@ -26,3 +28,109 @@ fn test_simulated_annealing_numeric_individual() {
         assert!(fitness >= 0.0);
     }
 }
+
+// Walks along a fixed fitness sequence: the only neighbour of step `k` is
+// step `k + 1`, so the run is fully deterministic.
+#[derive(Clone, Debug, Default)]
+struct Walk {
+    step: usize,
+}
+
+const WALK: [f64; 5] = [3.0, 1.0, 2.0, 0.0, 0.5];
+
+impl Individual for Walk {
+    type Fitness = f64;
+
+    fn fitness(&self) -> f64 {
+        WALK[self.step]
+    }
+}
+
+impl Neighbor for Walk {
+    fn neighbor(&mut self, _rng: &mut ThreadRng) -> Self {
+        Walk { step: self.step + 1 }
+    }
+}
+
+#[test]
+fn test_simulated_annealing_tracks_best_separately_from_current() {
+    // Threshold accepting at a constant threshold of 10 takes every move,
+    // including the uphill ones to 2.0 and 0.5.
+    let sa = SimulatedAnnealing::new(10.0, 0.0, WALK.len() - 1).with_acceptance(Acceptance::ThresholdAccepting);
+    let mut archive: BasicArchive<Walk> = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    assert_eq!(observers[0].values("current_fitness"), WALK.to_vec());
+    assert_eq!(observers[0].values("best_fitness"), vec![3.0, 1.0, 1.0, 0.0, 0.0]);
+    assert_eq!(archive.get_best().unwrap().fitness(), 0.0);
+}
+
+#[test]
+fn test_simulated_annealing_restarts_from_best() {
+    let sa = SimulatedAnnealing::new(1e6, 0.0, 300)
+        .with_start(NumericIndividual { genes: vec![0.5; 5] })
+        .with_restart_from_best(10);
+    let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    let metrics = &observers[0].metrics;
+    let restarts: Vec<usize> = metrics
+        .iter()
+        .filter(|(_, name, _)| name == "restart_from_best")
+        .map(|&(iteration, _, _)| iteration)
+        .collect();
+    assert!(!restarts.is_empty());
+    for iteration in restarts {
+        let value = |metric: &str| {
+            metrics
+                .iter()
+                .find(|(i, name, _)| *i == iteration && name == metric)
+                .map(|&(_, _, value)| value)
+                .unwrap()
+        };
+        assert_eq!(value("current_fitness"), value("best_fitness"));
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_cooling_rate_reads_geometric_schedule() {
+    let sa: SimulatedAnnealing<NumericIndividual> = SimulatedAnnealing::new(10.0, 0.05, 10);
+    assert_eq!(sa.cooling_rate(), Some(0.05));
+    let logarithmic = sa.with_schedule(CoolingSchedule::Logarithmic);
+    assert_eq!(logarithmic.cooling_rate(), None);
+}
+
+// Keeps the last best solution an optimizer reported.
+struct BestSolution {
+    best: Option<(usize, Walk)>,
+}
+
+impl Observer<Walk> for BestSolution {
+    fn on_start(&mut self) {}
+
+    fn on_iteration(&mut self, _iteration: usize, _population: &[Walk]) {}
+
+    fn on_best(&mut self, iteration: usize, best: &Walk) {
+        self.best = Some((iteration, best.clone()));
+    }
+
+    fn on_finish(&mut self) {}
+}
+
+#[test]
+fn test_simulated_annealing_reports_best_solution() {
+    let sa = SimulatedAnnealing::new(10.0, 0.0, WALK.len() - 1).with_acceptance(Acceptance::ThresholdAccepting);
+    let mut archive: BasicArchive<Walk> = BasicArchive::new(1);
+    let mut observers = vec![BestSolution { best: None }];
+
+    sa.optimize(&mut archive, &mut observers);
+
+    // The walk ends on 0.5, but the best state was step 3 with 0.0.
+    let (iteration, best) = observers[0].best.clone().unwrap();
+    assert_eq!((iteration, best.step), (3, 3));
+}
@@ -0,0 +1,44 @@
+use metaheuristics::genetic_algorithm::{GeneticAlgorithm, Replacement};
+use metaheuristics::selection::Tournament;
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::Individual;
+use metaheuristics::individuals::NumericIndividual;
+use metaheuristics::distribution_observers::DistributionObserver;
+
+fn best_per_generation(distributions: &[Vec<NumericIndividual>]) -> Vec<f64> {
+    distributions
+        .iter()
+        .map(|population| population.iter().map(|ind| ind.fitness()).fold(f64::INFINITY, f64::min))
+        .collect()
+}
+
+#[test]
+fn test_steady_state_replacement_policies() {
+    let policies = [
+        Replacement::Worst,
+        Replacement::Oldest,
+        Replacement::Tournament(3),
+        Replacement::ParentIfBetter,
+    ];
+
+    for replacement in policies {
+        let ga = GeneticAlgorithm::new(20, 0.5, 0.8, 15)
+            .with_selection(Tournament::new(2))
+            .with_steady_state(2, replacement);
+        let mut archive: BasicArchive<NumericIndividual> = BasicArchive::new(5);
+        let mut observers = vec![DistributionObserver::new()];
+
+        ga.optimize(&mut archive, &mut observers);
+
+        assert!(archive.get_best().is_some());
+        let distributions = &observers[0].distributions;
+        assert_eq!(distributions.len(), 16);
+        assert!(distributions.iter().all(|population| population.len() == 20));
+
+        if matches!(replacement, Replacement::Worst | Replacement::ParentIfBetter) {
+            let best = best_per_generation(distributions);
+            assert!(best.windows(2).all(|w| w[1] <= w[0]));
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+use metaheuristics::gp::{GpConfig, PrimitiveSet, Tree, Node, Value};
+use metaheuristics::symbolic_regression::{Dataset, DatasetError, SymbolicRegression, Metric, formula, r_squared};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::gp::TreeIndividual;
+use metaheuristics::logging_observers::LoggingObserver;
+
+fn linear_csv() -> String {
+    let mut csv = String::from("width, height, area\n");
+    for i in 0..20 {
+        let width = i as f64 / 4.0;
+        let height = 2.0 - i as f64 / 10.0;
+        csv.push_str(&format!("{}, {}, {}\n", width, height, width * 3.0 + height));
+    }
+    csv
+}
+
+#[test]
+fn test_dataset_parsing() {
+    let dataset = Dataset::from_csv_str(&linear_csv(), None).unwrap();
+    assert_eq!(dataset.feature_names, vec!["width", "height"]);
+    assert_eq!(dataset.len(), 20);
+    assert_eq!(dataset.features[4], vec![1.0, 1.6]);
+
+    let dataset = Dataset::from_csv_str("1,2,3\n4,5,6\n\n", Some("x0")).unwrap();
+    assert_eq!(dataset.feature_names, vec!["x1", "x2"]);
+    assert_eq!(dataset.targets, vec![1.0, 4.0]);
+
+    assert!(matches!(Dataset::from_csv_str("", None), Err(DatasetError::Empty)));
+    assert!(matches!(Dataset::from_csv_str("a,b\n1,2,3\n", None), Err(DatasetError::RaggedRow { line: 2, .. })));
+    assert!(matches!(Dataset::from_csv_str("1,2\n3,x\n", None), Err(DatasetError::Parse { line: 2, .. })));
+    assert!(matches!(Dataset::from_csv_str("1,oops\n3,4\n", None), Err(DatasetError::Parse { line: 1, .. })));
+    assert!(matches!(Dataset::from_csv_str("a,b\n1,2\n", Some("c")), Err(DatasetError::MissingTarget(_))));
+}
+
+#[test]
+fn test_formula_and_r_squared() {
+    let dataset = Dataset::from_csv_str(&linear_csv(), None).unwrap();
+    let regression = SymbolicRegression::new(dataset.clone());
+    let primitives = regression.primitives();
+    let plus = primitives.functions.iter().position(|f| f.name == "+").unwrap();
+    let width = primitives.terminals.iter().position(|t| t.name == "width").unwrap();
+    let height = primitives.terminals.iter().position(|t| t.name == "height").unwrap();
+    let tree = Tree::new(vec![Node::Function(plus), Node::Terminal(width), Node::Terminal(height)]);
+
+    assert_eq!(formula(&tree, &primitives), "(width + height)");
+    let minus = primitives.functions.iter().position(|f| f.name == "-").unwrap();
+    let times = primitives.functions.iter().position(|f| f.name == "*").unwrap();
+    let negative = Node::Constant(Value::Float(-0.5));
+    let difference = Tree::new(vec![Node::Function(minus), Node::Terminal(width), negative]);
+    assert_eq!(formula(&difference, &primitives), "(width + 0.5)");
+    let product = Tree::new(vec![Node::Function(times), Node::Terminal(width), negative]);
+    assert_eq!(formula(&product, &primitives), "(width * (-0.5))");
+    let fit = r_squared(&tree, &primitives, &dataset);
+    assert!(fit < 1.0);
+
+    let config = Arc::new(GpConfig::new(primitives, |_: &Tree, _: &PrimitiveSet| 0.0));
+    assert_eq!(regression.formula(&config.individual(tree)), "(width + height)");
+}
+
+// Evaluates a formula written by `formula` for `PrimitiveSet::arithmetic(1)`.
+fn evaluate_formula(tokens: &[&str], position: &mut usize, x: f64) -> f64 {
+    let token = tokens[*position];
+    *position += 1;
+    match token {
+        "x0" => x,
+        "(" => {
+            let left = evaluate_formula(tokens, position, x);
+            if tokens[*position] == ")" {
+                *position += 1;
+                return left;
+            }
+            let operator = tokens[*position];
+            *position += 1;
+            let right = evaluate_formula(tokens, position, x);
+            assert_eq!(tokens[*position], ")");
+            *position += 1;
+            match operator {
+                "+" => left + right,
+                "-" => left - right,
+                "*" => left * right,
+                "/" => if right.abs() < 1e-9 { 1.0 } else { left / right },
+                other => panic!("unexpected operator {}", other),
+            }
+        }
+        number => number.parse().unwrap(),
+    }
+}
+
+#[test]
+fn test_formula_constants_read_back_as_the_scored_model() {
+    let primitives = PrimitiveSet::arithmetic(1);
+    let function = |name: &str| Node::Function(primitives.functions.iter().position(|f| f.name == name).unwrap());
+    let x = Node::Terminal(primitives.terminals.iter().position(|t| t.name == "x0").unwrap());
+    let constant = |value: f64| Node::Constant(Value::Float(value));
+    let tree = Tree::new(vec![
+        function("+"),
+        function("*"), x, constant(1e-5),
+        function("-"),
+        function("/"), x, constant(-3.2e-7),
+        function("+"), constant(0.1 + 0.2), constant(-1e-5),
+    ]);
+
+    let written = formula(&tree, &primitives);
+    assert!(!written.contains("0.0000)"), "{}", written);
+    let spaced = written.replace('(', " ( ").replace(')', " ) ");
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+    for x in [-2.0, 0.0, 0.3, 7.5] {
+        let mut position = 0;
+        let read_back = evaluate_formula(&tokens, &mut position, x);
+        assert_eq!(position, tokens.len());
+        assert_eq!(read_back, tree.evaluate(&primitives, &[x]).as_f64(), "{}", written);
+    }
+}
+
+#[test]
+fn test_symbolic_regression_finds_linear_model() {
+    let dataset = Dataset::from_csv_str(&linear_csv(), None).unwrap();
+    let regression = SymbolicRegression::new(dataset)
+        .with_metric(Metric::RSquared)
+        .with_parsimony(1e-4);
+    let ga = regression.genetic_algorithm(200, 30);
+    let mut archive: BasicArchive<TreeIndividual> = BasicArchive::new(5);
+    let mut observers = vec![LoggingObserver::new(false, false, false)];
+
+    ga.optimize(&mut archive, &mut observers);
+
+    let best = archive.get_best().unwrap();
+    assert!(regression.r_squared(best) > 0.95, "{} has R² {}", regression.formula(best), regression.r_squared(best));
+    assert!(regression.mse(best).is_finite());
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use rand::prelude::*;
+use metaheuristics::tabu_search::{TabuSearch, FrequencyMemory};
+use metaheuristics::optimizer::Optimizer;
+use metaheuristics::archive::{BasicArchive, Archive};
+use metaheuristics::individual::{Individual, Neighborhood};
+use metaheuristics::individuals::{BitStringIndividual, TourIndividual};
+use metaheuristics::metric_observers::MetricObserver;
+use metaheuristics::initializer::Initializer;
+use metaheuristics::observer::Observer;
+
+// Records which bits have ever been set in the current solution.
+#[derive(Default)]
+struct BitsTouched {
+    touched: Vec<bool>,
+}
+
+impl Observer<BitStringIndividual> for BitsTouched {
+    fn on_start(&mut self) {}
+
+    fn on_iteration(&mut self, _iteration: usize, population: &[BitStringIndividual]) {
+        let bits = &population[0].bits;
+        self.touched.resize(bits.len(), false);
+        for (touched, &bit) in self.touched.iter_mut().zip(bits) {
+            *touched |= bit;
+        }
+    }
+
+    fn on_finish(&mut self) {}
+}
+
+fn bits(ones: usize, length: usize) -> BitStringIndividual {
+    BitStringIndividual { bits: (0..length).map(|i| i < ones).collect() }
+}
+
+#[test]
+fn test_tabu_search_clears_bit_string() {
+    let tabu = TabuSearch::new(30, 3).with_start(bits(20, 20));
+    let mut archive = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    tabu.optimize(&mut archive, &mut observers);
+
+    assert_eq!(archive.get_best().unwrap().fitness(), 0.0);
+    assert_eq!(observers[0].values("best_fitness").last(), Some(&0.0));
+}
+
+#[test]
+fn test_tenure_keeps_moves_from_being_undone() {
+    let tabu = TabuSearch::new(8, 5).with_start(bits(0, 20));
+    let mut archive = BasicArchive::new(1);
+    let mut observers = vec![MetricObserver::new()];
+
+    tabu.optimize(&mut archive, &mut observers);
+
+    // From the optimum every move worsens; the first flip may only be undone
+    // once its five tabu iterations are over.
+    let current = observers[0].values("current_fitness");
+    assert_eq!(current[..7], [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 5.0]);
+    assert_eq!(observers[0].values("tabu_size")[0], 1.0);
+}
+
+#[test]
+fn test_two_opt_tabu_search_untangles_convex_tour() {
+    let cities = 12;
+    let points: Vec<(f64, f64)> = (0..cities)
+        .map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * i as f64 / cities as f64;
+            (angle.cos(), angle.sin())
+        })
+        .collect();
+    let distances: Vec<Vec<f64>> = points
+        .iter()
+        .map(|a| points.iter().map(|b| ((a.0 - b.0).powi(2) + (a.1 - b.1).powi(2)).sqrt()).collect())
+        .collect();
+    let optimum = cities as f64 * distances[0][1];
+    let start = TourIndividual::random(Arc::new(distances), &mut thread_rng());
+    assert_eq!(start.moves().len(), cities * (cities - 1) / 2 - 1);
+
+    let tabu = TabuSearch::from_initializer(Initializer::from_population(vec![start]), 100, 7);
+    let mut archive = BasicArchive::new(1);
+    let mut observers: Vec<MetricObserver<TourIndividual>> = Vec::new();
+
+    tabu.optimize(&mut archive, &mut observers);
+
+    assert!((archive.get_best().unwrap().fitness() - optimum).abs() < 1e-9);
+}
+
+#[test]
+fn test_diversification_spreads_moves_over_more_attributes() {
+    let touched = |memory: FrequencyMemory| {
+        let tabu = TabuSearch::new(40, 1).with_start(bits(0, 20)).with_memory(memory);
+        let mut archive = BasicArchive::new(1);
+        let mut observers = vec![BitsTouched::default()];
+        tabu.optimize(&mut archive, &mut observers);
+        observers[0].touched.iter().filter(|&&touched| touched).count()
+    };
+
+    let plain = touched(FrequencyMemory::None);
+    let diversified = touched(FrequencyMemory::Diversification { weight: 10.0 });
+    assert!(diversified > plain, "{} vs {}", diversified, plain);
+}